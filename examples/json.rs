@@ -21,6 +21,7 @@ fn main() {
 }
 
 #[derive(Debug)]
+#[allow(dead_code)]
 enum Value<'a> {
     Null,
     Bool(bool),
@@ -4,10 +4,12 @@
 //! followed by a single byte that denotes the the UTF-8 body length we need to
 //! read. Our protocol expects a version of `1`.
 
-use std::error::Error as StdError;
+#![allow(clippy::result_large_err)]
+
 use std::io;
 
-use dangerous::{BytesReader, Error, Expected, Input, Invalid, ToRetryRequirement};
+use dangerous::stream::{StreamDecoder, StreamError};
+use dangerous::{BytesReader, Error};
 
 const VALID_MESSAGE: &[u8] = &[
     0x01, // version: 1
@@ -22,84 +24,36 @@ const INVALID_MESSAGE: &[u8] = &[
 ];
 
 #[derive(Debug)]
-struct Message<'a> {
-    body: &'a str,
+struct Message {
+    body: String,
 }
 
 fn main() {
-    let mut decoder = Decoder::new();
-
     // Read a valid message
-    let message = decoder
-        .read_and_decode_message(&mut Stream::new(VALID_MESSAGE))
-        .unwrap();
+    let message = read_and_decode_message(Stream::new(VALID_MESSAGE)).unwrap();
 
     println!("{}", message.body);
 
     // Read a invalid message
-    let err = decoder
-        .read_and_decode_message(&mut Stream::new(INVALID_MESSAGE))
-        .unwrap_err();
+    let err = read_and_decode_message(Stream::new(INVALID_MESSAGE)).unwrap_err();
 
     eprintln!("error reading message: {}", err);
 }
 
-pub struct Decoder {
-    buf: [u8; 256],
-}
-
-impl Decoder {
-    fn new() -> Self {
-        Self { buf: [0u8; 256] }
-    }
-
-    fn read_and_decode_message<'i, R>(
-        &'i mut self,
-        mut read: R,
-    ) -> Result<Message<'i>, Box<dyn StdError + 'i>>
-    where
-        R: io::Read,
-    {
-        let mut written_cur = 0;
-        let mut expects_cur = 0;
-        loop {
-            // Read bytes into buffer
-            written_cur += read.read(&mut self.buf[written_cur..])?;
-            // Only decode the buffer if we have enough bytes to try again
-            if expects_cur > written_cur {
-                println!(
-                    "not enough to decode, waiting for {} bytes",
-                    expects_cur - written_cur
-                );
-                continue;
-            }
-            // Try and decode the input, working out if we need more, or the
-            // input is invalid.
-            //
-            // FIXME: This would realistically return the decoded message or any
-            // error, but we can't mut borrow and return immutable within a loop
-            // yet. See: https://github.com/rust-lang/rust/issues/51132
-            let input = dangerous::input(&self.buf[..written_cur]);
-            match input.read_all(decode_message) {
-                Err(err) => match Invalid::to_retry_requirement(&err) {
-                    Some(req) => {
-                        expects_cur += req.continue_after();
-                        continue;
-                    }
-                    None => break,
-                },
-                Ok(_) => break,
-            }
-        }
-        // Decode the input returning the message or any error, see above why
-        // this is required.
-        dangerous::input(&self.buf[..written_cur])
-            .read_all(decode_message)
-            .map_err(Box::<Expected>::into)
-    }
+fn read_and_decode_message<R>(read: R) -> Result<Message, StreamError>
+where
+    R: io::Read,
+{
+    // The decoder reads from the stream until it has enough input to decode
+    // the message, using the retry requirement of the error to work out how
+    // many more bytes it needs.
+    let mut decoder = StreamDecoder::new(read).with_max_buffer_len(256);
+    decoder
+        .decode(|r| decode_message(r))?
+        .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof).into())
 }
 
-fn decode_message<'i, E>(r: &mut BytesReader<'i, E>) -> Result<Message<'i>, E>
+fn decode_message<'i, E>(r: &mut BytesReader<'i, E>) -> Result<Message, E>
 where
     E: Error<'i>,
 {
@@ -115,7 +69,9 @@ where
             body_input.to_dangerous_str()
         })?;
         // We did it!
-        Ok(Message { body })
+        Ok(Message {
+            body: body.to_owned(),
+        })
     })
 }
 
@@ -153,6 +109,6 @@ mod tests {
     #[test]
     fn test_message_size() {
         // If true, we box Expected!
-        assert!(core::mem::size_of::<Message<'_>>() < 128);
+        assert!(core::mem::size_of::<Message>() < 128);
     }
 }
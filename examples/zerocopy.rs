@@ -3,6 +3,7 @@ use dangerous::{BytesReader, Error, Expected, Input};
 use zc::Dependant;
 
 #[derive(Dependant, Debug)]
+#[allow(dead_code)]
 pub struct ParsedResult<'a>(Vec<&'a str>);
 
 impl<'a> TryFrom<&'a [u8]> for ParsedResult<'a> {
@@ -0,0 +1,163 @@
+use crate::fmt;
+
+use super::Checksum;
+
+static CRC32_IEEE_TABLE: [u32; 256] = crc32_reflected_table(0xedb8_8320);
+static CRC32_CASTAGNOLI_TABLE: [u32; 256] = crc32_reflected_table(0x82f6_3b78);
+static CRC16_CCITT_TABLE: [u16; 256] = crc16_table(0x1021);
+
+/// A 32-bit cyclic redundancy check.
+#[derive(Clone)]
+pub struct Crc32 {
+    name: &'static str,
+    table: &'static [u32; 256],
+    state: u32,
+}
+
+impl Crc32 {
+    /// CRC-32/ISO-HDLC as used in PNG, gzip, zip and Ethernet.
+    #[must_use]
+    pub fn ieee() -> Self {
+        Self {
+            name: "ieee",
+            table: &CRC32_IEEE_TABLE,
+            state: !0,
+        }
+    }
+
+    /// CRC-32C as used in iSCSI, SCTP and ext4.
+    #[must_use]
+    pub fn castagnoli() -> Self {
+        Self {
+            name: "castagnoli",
+            table: &CRC32_CASTAGNOLI_TABLE,
+            state: !0,
+        }
+    }
+}
+
+impl Checksum for Crc32 {
+    fn width(&self) -> usize {
+        4
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        let mut state = self.state;
+        for b in bytes.iter().copied() {
+            state = self.table[((state ^ u32::from(b)) & 0xff) as usize] ^ (state >> 8);
+        }
+        self.state = state;
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(!self.state)
+    }
+}
+
+impl fmt::Debug for Crc32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Crc32")
+            .field("name", &self.name)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A 16-bit cyclic redundancy check with the CCITT polynomial (`0x1021`).
+#[derive(Clone)]
+pub struct Crc16 {
+    name: &'static str,
+    state: u16,
+}
+
+impl Crc16 {
+    /// CRC-16/CCITT-FALSE (also known as CRC-16/IBM-3740) with an initial
+    /// value of `0xffff`.
+    #[must_use]
+    pub fn ccitt_false() -> Self {
+        Self {
+            name: "ccitt-false",
+            state: 0xffff,
+        }
+    }
+
+    /// CRC-16/XMODEM with an initial value of `0x0000`.
+    #[must_use]
+    pub fn xmodem() -> Self {
+        Self {
+            name: "xmodem",
+            state: 0,
+        }
+    }
+}
+
+impl Checksum for Crc16 {
+    fn width(&self) -> usize {
+        2
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        let mut state = self.state;
+        for b in bytes.iter().copied() {
+            state = CRC16_CCITT_TABLE[usize::from((state >> 8) ^ u16::from(b))] ^ (state << 8);
+        }
+        self.state = state;
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.state)
+    }
+}
+
+impl fmt::Debug for Crc16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Crc16")
+            .field("name", &self.name)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+const fn crc32_reflected_table(poly: u32) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+const fn crc16_table(poly: u16) -> [u16; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 == 0x8000 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
@@ -0,0 +1,59 @@
+//! Checksum support.
+//!
+//! Checksums are verified with [`BytesReader::verify_checksum()`], which
+//! calculates a [`Checksum`] over the input consumed within its scope and
+//! compares it against the checksum that immediately follows.
+//!
+//! The trailing checksum is read in the byte order of [`Reader::endian()`].
+//!
+//! | Algorithm                    | Width   | Check value (`"123456789"`) |
+//! | ---------------------------- | ------- | --------------------------- |
+//! | [`Crc32::ieee()`]            | 4 bytes | `0xcbf43926`                |
+//! | [`Crc32::castagnoli()`]      | 4 bytes | `0xe3069283`                |
+//! | [`Crc16::ccitt_false()`]     | 2 bytes | `0x29b1`                    |
+//! | [`Crc16::xmodem()`]          | 2 bytes | `0x31c3`                    |
+//! | [`Adler32`]                  | 4 bytes | `0x091e01de`                |
+//! | [`Xor8`]                     | 1 byte  | `0x31`                      |
+//! | [`Sum8`]                     | 1 byte  | `0xdd`                      |
+//!
+//! Custom algorithms can be used by implementing [`Checksum`].
+//!
+//! [`BytesReader::verify_checksum()`]: crate::BytesReader::verify_checksum()
+//! [`Reader::endian()`]: crate::Reader::endian()
+
+mod crc;
+mod simple;
+
+pub use self::crc::{Crc16, Crc32};
+pub use self::simple::{Adler32, Sum8, Xor8};
+
+/// Implemented for checksum algorithms.
+pub trait Checksum {
+    /// The number of bytes the checksum is encoded with.
+    ///
+    /// The width must not be greater than `8`.
+    fn width(&self) -> usize;
+
+    /// Updates the checksum with the provided bytes.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the checksum of all bytes provided so far.
+    fn finish(&self) -> u64;
+}
+
+impl<T> Checksum for &mut T
+where
+    T: Checksum,
+{
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        (**self).update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        (**self).finish()
+    }
+}
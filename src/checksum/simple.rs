@@ -0,0 +1,122 @@
+use crate::fmt;
+
+use super::Checksum;
+
+/// Adler-32 as used in zlib.
+#[derive(Clone)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Adler32 {
+    const MOD: u32 = 65521;
+    /// Largest number of bytes that can be summed before `b` may overflow.
+    const CHUNK_LEN: usize = 5552;
+
+    /// Creates a new `Adler32` checksum.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Adler32 {
+    fn width(&self) -> usize {
+        4
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(Self::CHUNK_LEN) {
+            for b in chunk.iter().copied() {
+                self.a += u32::from(b);
+                self.b += self.a;
+            }
+            self.a %= Self::MOD;
+            self.b %= Self::MOD;
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from((self.b << 16) | self.a)
+    }
+}
+
+impl fmt::Debug for Adler32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Adler32")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+/// A single byte checksum of all bytes combined with exclusive or.
+#[derive(Clone, Default)]
+pub struct Xor8(u8);
+
+impl Xor8 {
+    /// Creates a new `Xor8` checksum.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(0)
+    }
+}
+
+impl Checksum for Xor8 {
+    fn width(&self) -> usize {
+        1
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |acc, b| acc ^ b);
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.0)
+    }
+}
+
+impl fmt::Debug for Xor8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Xor8").field(&self.0).finish()
+    }
+}
+
+/// A single byte checksum of all bytes summed together with wrapping.
+#[derive(Clone, Default)]
+pub struct Sum8(u8);
+
+impl Sum8 {
+    /// Creates a new `Sum8` checksum.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(0)
+    }
+}
+
+impl Checksum for Sum8 {
+    fn width(&self) -> usize {
+        1
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |acc, b| acc.wrapping_add(*b));
+    }
+
+    fn finish(&self) -> u64 {
+        u64::from(self.0)
+    }
+}
+
+impl fmt::Debug for Sum8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sum8").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,840 @@
+//! Serde deserialization.
+//!
+//! Types deriving [`Deserialize`] can be read with a [`Format`], a grammar
+//! for reading primitives written with a [`BytesReader`]. The structure of
+//! the type drives which primitives are read, and errors are returned as
+//! [`Expected`] with a context for each struct, field, enum and variant the
+//! error occurred within.
+//!
+//! Formats are not self-describing, so `deserialize_any()` is not supported.
+//! [`Postcard`] is provided for the [postcard] wire format.
+//!
+//! # Example
+//!
+//! ```
+//! use dangerous::{de, Expected, Input};
+//! use dangerous::de::Postcard;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! struct Point<'a> {
+//!     name: &'a str,
+//!     x: u16,
+//!     y: i32,
+//! }
+//!
+//! let input = dangerous::input(b"\x06origin\x80\x01\x03");
+//! let point: Result<Point<'_>, Expected<'_>> = input.read_all(|r| de::deserialize(r, Postcard));
+//!
+//! assert_eq!(point.unwrap(), Point { name: "origin", x: 128, y: -2 });
+//! ```
+//!
+//! [`Deserialize`]: serde::Deserialize
+//! [postcard]: https://postcard.jamesmunns.com/wire-format
+
+// Serde requires a concrete error type, returned from every method here.
+#![allow(clippy::result_large_err)]
+
+mod postcard;
+
+pub use self::postcard::Postcard;
+
+use core::any::type_name;
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::Deserialize;
+
+use crate::error::{Context, Error, ExpectedContext, ExpectedValid};
+use crate::fmt;
+use crate::input::Input;
+use crate::{BytesReader, Expected};
+
+/// The default maximum depth of nested values, see [`deserialize()`].
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// Deserializes a value with the given [`Format`].
+///
+/// Structs, enums, options, sequences, tuples and maps can be nested at most
+/// [`DEFAULT_MAX_DEPTH`] deep, so a recursive type can't overflow the stack.
+/// Use [`deserialize_with_max_depth()`] to change the limit.
+///
+/// # Errors
+///
+/// Returns an error if the input did not match the format, the value read
+/// was rejected by its [`Deserialize`] implementation, or the value was
+/// nested deeper than the limit.
+pub fn deserialize<'i, T, F>(
+    r: &mut BytesReader<'i, Expected<'i>>,
+    format: F,
+) -> Result<T, Expected<'i>>
+where
+    T: Deserialize<'i>,
+    F: Format<'i>,
+{
+    deserialize_with_max_depth(r, format, DEFAULT_MAX_DEPTH)
+}
+
+/// Deserializes a value with the given [`Format`] and maximum depth of
+/// nested values.
+///
+/// # Errors
+///
+/// Returns an error if the input did not match the format, the value read
+/// was rejected by its [`Deserialize`] implementation, or the value was
+/// nested deeper than `max_depth`.
+pub fn deserialize_with_max_depth<'i, T, F>(
+    r: &mut BytesReader<'i, Expected<'i>>,
+    mut format: F,
+    max_depth: usize,
+) -> Result<T, Expected<'i>>
+where
+    T: Deserialize<'i>,
+    F: Format<'i>,
+{
+    let context = ExpectedContext {
+        operation: "deserialize",
+        expected: type_name::<T>(),
+    };
+    r.context(context, |r| {
+        Deserializer {
+            r,
+            format: &mut format,
+            depth: max_depth,
+        }
+        .consume(|de| T::deserialize(de))
+    })
+}
+
+/// A grammar for reading the primitives of the serde data model.
+///
+/// Lengths are read for strings, byte arrays, sequences and maps, and
+/// variant indexes are read for enums.
+pub trait Format<'i> {
+    /// Read a `bool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `bool` could not be read.
+    fn read_bool<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<bool, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `i8`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `i8` could not be read.
+    fn read_i8<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i8, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `i16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `i16` could not be read.
+    fn read_i16<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i16, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `i32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `i32` could not be read.
+    fn read_i32<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i32, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `i64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `i64` could not be read.
+    fn read_i64<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i64, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `i128`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `i128` could not be read.
+    fn read_i128<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i128, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `u8`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `u8` could not be read.
+    fn read_u8<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u8, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `u16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `u16` could not be read.
+    fn read_u16<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u16, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `u32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `u32` could not be read.
+    fn read_u32<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u32, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `u64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `u64` could not be read.
+    fn read_u64<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u64, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `u128`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `u128` could not be read.
+    fn read_u128<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u128, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `f32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `f32` could not be read.
+    fn read_f32<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<f32, E>
+    where
+        E: Error<'i>;
+
+    /// Read an `f64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `f64` could not be read.
+    fn read_f64<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<f64, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `char`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `char` could not be read.
+    fn read_char<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<char, E>
+    where
+        E: Error<'i>;
+
+    /// Read a `str` borrowed from the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a `str` could not be read.
+    fn read_str<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<&'i str, E>
+    where
+        E: Error<'i>;
+
+    /// Read a byte array borrowed from the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a byte array could not be read.
+    fn read_bytes<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<&'i [u8], E>
+    where
+        E: Error<'i>;
+
+    /// Read whether an option is `Some`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an option tag could not be read.
+    fn read_option<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<bool, E>
+    where
+        E: Error<'i>;
+
+    /// Read the length of a sequence or map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a length could not be read.
+    fn read_len<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<usize, E>
+    where
+        E: Error<'i>;
+
+    /// Read the index of an enum variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variant index could not be read.
+    fn read_variant<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u32, E>
+    where
+        E: Error<'i>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Deserializer
+
+/// A serde [`Deserializer`](serde::Deserializer) reading from a
+/// [`BytesReader`] with a [`Format`].
+struct Deserializer<'r, 'i, F> {
+    r: &'r mut BytesReader<'i, Expected<'i>>,
+    format: &'r mut F,
+    /// The remaining depth values can be nested within.
+    depth: usize,
+}
+
+impl<'i, F> Deserializer<'_, 'i, F> {
+    /// Consumes input with `f`, converting a value rejected by serde into an
+    /// error spanning the input consumed for it.
+    fn consume<T, G>(&mut self, f: G) -> Result<T, Expected<'i>>
+    where
+        G: FnOnce(&mut Deserializer<'_, 'i, F>) -> Result<T, DeserializeError<'i>>,
+    {
+        let format = &mut *self.format;
+        let depth = self.depth;
+        let mut result = None;
+        let consumed = self
+            .r
+            .take_consumed(|r| result = Some(f(&mut Deserializer { r, format, depth })));
+        let expected = match result {
+            Some(Ok(value)) => return Ok(value),
+            Some(Err(DeserializeError(ErrorKind::Expected(err)))) => return Err(err),
+            Some(Err(DeserializeError(ErrorKind::Rejected(expected)))) => expected,
+            None => "value",
+        };
+        Err(Expected::from(ExpectedValid {
+            span: consumed.as_dangerous(),
+            input: consumed.into_maybe_string(),
+            context: ExpectedContext {
+                operation: "deserialize",
+                expected,
+            },
+            #[cfg(feature = "retry")]
+            retry_requirement: None,
+        }))
+    }
+
+    /// Consumes input with `f` within the given context.
+    fn scope<T, G>(&mut self, context: impl Context, f: G) -> Result<T, DeserializeError<'i>>
+    where
+        G: FnOnce(&mut Deserializer<'_, 'i, F>) -> Result<T, DeserializeError<'i>>,
+    {
+        let format = &mut *self.format;
+        let depth = self.depth;
+        self.r
+            .context(context, |r| Deserializer { r, format, depth }.consume(f))
+            .map_err(DeserializeError::from)
+    }
+
+    /// Enters a value that can contain other values, returning an error if
+    /// the depth limit is exceeded.
+    fn enter(&mut self) -> Result<(), DeserializeError<'i>> {
+        if let Some(depth) = self.depth.checked_sub(1) {
+            self.depth = depth;
+            return Ok(());
+        }
+        let span = self.r.take_consumed(|_| ());
+        Err(DeserializeError::from(Expected::from(ExpectedValid {
+            span: span.as_dangerous(),
+            input: span.into_maybe_string(),
+            context: ExpectedContext {
+                operation: "deserialize",
+                expected: "value nested within the depth limit",
+            },
+            #[cfg(feature = "retry")]
+            retry_requirement: None,
+        })))
+    }
+}
+
+impl<F> fmt::Debug for Deserializer<'_, '_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Deserializer")
+            .field("r", &self.r)
+            .field("depth", &self.depth)
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! impl_deserialize_primitive {
+    ($($deserialize:ident => $read:ident, $visit:ident;)*) => {
+        $(
+            fn $deserialize<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'i>,
+            {
+                self.consume(|de| visitor.$visit(de.format.$read(de.r)?))
+                    .map_err(DeserializeError::from)
+            }
+        )*
+    };
+}
+
+impl<'i, F> de::Deserializer<'i> for &mut Deserializer<'_, 'i, F>
+where
+    F: Format<'i>,
+{
+    type Error = DeserializeError<'i>;
+
+    impl_deserialize_primitive! {
+        deserialize_bool => read_bool, visit_bool;
+        deserialize_i8 => read_i8, visit_i8;
+        deserialize_i16 => read_i16, visit_i16;
+        deserialize_i32 => read_i32, visit_i32;
+        deserialize_i64 => read_i64, visit_i64;
+        deserialize_i128 => read_i128, visit_i128;
+        deserialize_u8 => read_u8, visit_u8;
+        deserialize_u16 => read_u16, visit_u16;
+        deserialize_u32 => read_u32, visit_u32;
+        deserialize_u64 => read_u64, visit_u64;
+        deserialize_u128 => read_u128, visit_u128;
+        deserialize_f32 => read_f32, visit_f32;
+        deserialize_f64 => read_f64, visit_f64;
+        deserialize_char => read_char, visit_char;
+        deserialize_str => read_str, visit_borrowed_str;
+        deserialize_string => read_str, visit_borrowed_str;
+        deserialize_bytes => read_bytes, visit_borrowed_bytes;
+        deserialize_byte_buf => read_bytes, visit_borrowed_bytes;
+        deserialize_identifier => read_variant, visit_u32;
+    }
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        Err(DeserializeError(ErrorKind::Rejected(
+            "self-describing format",
+        )))
+    }
+
+    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        Err(DeserializeError(ErrorKind::Rejected(
+            "self-describing format",
+        )))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.consume(|de| {
+            if de.format.read_option(de.r)? {
+                visitor.visit_some(de)
+            } else {
+                visitor.visit_none()
+            }
+        })
+        .map_err(DeserializeError::from)
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.consume(|_| visitor.visit_unit())
+            .map_err(DeserializeError::from)
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.scope(struct_context(name), |_| visitor.visit_unit())
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.scope(struct_context(name), |de| visitor.visit_newtype_struct(de))
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.consume(|de| {
+            let len = de.format.read_len(de.r)?;
+            visitor.visit_seq(Seq::new(de, len, &[]))
+        })
+        .map_err(DeserializeError::from)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.consume(|de| visitor.visit_seq(Seq::new(de, len, &[])))
+            .map_err(DeserializeError::from)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.scope(struct_context(name), |de| {
+            visitor.visit_seq(Seq::new(de, len, &[]))
+        })
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.consume(|de| {
+            let len = de.format.read_len(de.r)?;
+            visitor.visit_map(Map { de, len })
+        })
+        .map_err(DeserializeError::from)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        self.scope(struct_context(name), |de| {
+            visitor.visit_seq(Seq::new(de, fields.len(), fields))
+        })
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        self.enter()?;
+        let context = ExpectedContext {
+            operation: "deserialize enum",
+            expected: name,
+        };
+        self.scope(context, |de| visitor.visit_enum(Enum { de, variants }))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+fn struct_context(name: &'static str) -> ExpectedContext {
+    ExpectedContext {
+        operation: "deserialize struct",
+        expected: name,
+    }
+}
+
+/// Sequence, tuple and struct access, with the field names of a struct.
+struct Seq<'a, 'r, 'i, F> {
+    de: &'a mut Deserializer<'r, 'i, F>,
+    len: usize,
+    fields: &'static [&'static str],
+    index: usize,
+}
+
+impl<'a, 'r, 'i, F> Seq<'a, 'r, 'i, F> {
+    fn new(
+        de: &'a mut Deserializer<'r, 'i, F>,
+        len: usize,
+        fields: &'static [&'static str],
+    ) -> Self {
+        Self {
+            de,
+            len,
+            fields,
+            index: 0,
+        }
+    }
+}
+
+impl<'i, F> SeqAccess<'i> for Seq<'_, '_, 'i, F>
+where
+    F: Format<'i>,
+{
+    type Error = DeserializeError<'i>;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'i>,
+    {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let field = self.fields.get(self.index);
+        self.index += 1;
+        match field {
+            Some(field) => {
+                let context = ExpectedContext {
+                    operation: "deserialize field",
+                    expected: field,
+                };
+                self.de.scope(context, |de| seed.deserialize(de))
+            }
+            None => self
+                .de
+                .consume(|de| seed.deserialize(de))
+                .map_err(DeserializeError::from),
+        }
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// Map access.
+struct Map<'a, 'r, 'i, F> {
+    de: &'a mut Deserializer<'r, 'i, F>,
+    len: usize,
+}
+
+impl<'i, F> MapAccess<'i> for Map<'_, '_, 'i, F>
+where
+    F: Format<'i>,
+{
+    type Error = DeserializeError<'i>;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'i>,
+    {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        self.de
+            .consume(|de| seed.deserialize(de))
+            .map(Some)
+            .map_err(DeserializeError::from)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'i>,
+    {
+        self.de
+            .consume(|de| seed.deserialize(de))
+            .map_err(DeserializeError::from)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+/// Enum access, with the names of the variants.
+struct Enum<'a, 'r, 'i, F> {
+    de: &'a mut Deserializer<'r, 'i, F>,
+    variants: &'static [&'static str],
+}
+
+impl<'a, 'r, 'i, F> EnumAccess<'i> for Enum<'a, 'r, 'i, F>
+where
+    F: Format<'i>,
+{
+    type Error = DeserializeError<'i>;
+    type Variant = Variant<'a, 'r, 'i, F>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'i>,
+    {
+        let mut index = 0;
+        let value = self.de.consume(|de| {
+            index = de.format.read_variant(de.r)?;
+            seed.deserialize(IntoDeserializer::<Self::Error>::into_deserializer(index))
+        })?;
+        let name = self
+            .variants
+            .get(index as usize)
+            .copied()
+            .unwrap_or("variant");
+        Ok((value, Variant { de: self.de, name }))
+    }
+}
+
+/// Variant access, with the name of the variant.
+struct Variant<'a, 'r, 'i, F> {
+    de: &'a mut Deserializer<'r, 'i, F>,
+    name: &'static str,
+}
+
+impl<F> Variant<'_, '_, '_, F> {
+    fn context(&self) -> ExpectedContext {
+        ExpectedContext {
+            operation: "deserialize variant",
+            expected: self.name,
+        }
+    }
+}
+
+impl<'i, F> VariantAccess<'i> for Variant<'_, '_, 'i, F>
+where
+    F: Format<'i>,
+{
+    type Error = DeserializeError<'i>;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'i>,
+    {
+        let context = self.context();
+        self.de.scope(context, |de| seed.deserialize(de))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        let context = self.context();
+        self.de
+            .scope(context, |de| visitor.visit_seq(Seq::new(de, len, &[])))
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'i>,
+    {
+        let context = self.context();
+        self.de.scope(context, |de| {
+            visitor.visit_seq(Seq::new(de, fields.len(), fields))
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Error
+
+/// An error from deserializing a value.
+///
+/// Errors are returned from [`deserialize()`] as [`Expected`], values
+/// rejected by a [`Deserialize`] implementation are converted into an error
+/// spanning the input they were read from.
+#[must_use = "error must be handled"]
+pub struct DeserializeError<'i>(ErrorKind<'i>);
+
+enum ErrorKind<'i> {
+    /// The input did not match the format.
+    Expected(Expected<'i>),
+    /// The value read was rejected, with a description of what was expected.
+    Rejected(&'static str),
+}
+
+impl<'i> From<Expected<'i>> for DeserializeError<'i> {
+    fn from(err: Expected<'i>) -> Self {
+        Self(ErrorKind::Expected(err))
+    }
+}
+
+impl de::Error for DeserializeError<'_> {
+    fn custom<T>(_msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self(ErrorKind::Rejected("valid value"))
+    }
+
+    fn invalid_type(_unexp: de::Unexpected<'_>, _exp: &dyn de::Expected) -> Self {
+        Self(ErrorKind::Rejected("value of valid type"))
+    }
+
+    fn invalid_value(_unexp: de::Unexpected<'_>, _exp: &dyn de::Expected) -> Self {
+        Self(ErrorKind::Rejected("valid value"))
+    }
+
+    fn invalid_length(_len: usize, _exp: &dyn de::Expected) -> Self {
+        Self(ErrorKind::Rejected("valid length"))
+    }
+
+    fn unknown_variant(_variant: &str, _expected: &'static [&'static str]) -> Self {
+        Self(ErrorKind::Rejected("known variant"))
+    }
+
+    fn unknown_field(_field: &str, _expected: &'static [&'static str]) -> Self {
+        Self(ErrorKind::Rejected("known field"))
+    }
+
+    fn missing_field(_field: &'static str) -> Self {
+        Self(ErrorKind::Rejected("all fields"))
+    }
+
+    fn duplicate_field(_field: &'static str) -> Self {
+        Self(ErrorKind::Rejected("unique fields"))
+    }
+}
+
+impl fmt::Debug for DeserializeError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            ErrorKind::Expected(err) => f.debug_tuple("Expected").field(err).finish(),
+            ErrorKind::Rejected(expected) => f.debug_tuple("Rejected").field(expected).finish(),
+        }
+    }
+}
+
+impl fmt::Display for DeserializeError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            ErrorKind::Expected(err) => fmt::Display::fmt(err, f),
+            ErrorKind::Rejected(expected) => {
+                f.write_str("value rejected, expected ")?;
+                f.write_str(expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError<'_> {}
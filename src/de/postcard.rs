@@ -0,0 +1,172 @@
+use core::convert::TryFrom;
+use core::mem::size_of;
+
+use crate::error::Error;
+use crate::BytesReader;
+
+use super::Format;
+
+/// The [postcard] wire format.
+///
+/// - Integers wider than a byte are varints, zigzag encoded if signed.
+/// - Floats are little endian.
+/// - Lengths and variant indexes are varints.
+/// - A `char` is a length prefixed UTF-8 `str`.
+///
+/// [postcard]: https://postcard.jamesmunns.com/wire-format
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Postcard;
+
+macro_rules! impl_read_varint {
+    ($($read:ident => $ty:ty, $expected:literal;)*) => {
+        $(
+            fn $read<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<$ty, E>
+            where
+                E: Error<'i>,
+            {
+                read_varint(r, $expected)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_read_zigzag {
+    ($($read:ident => $ty:ty, $unsigned:ty, $expected:literal;)*) => {
+        $(
+            fn $read<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<$ty, E>
+            where
+                E: Error<'i>,
+            {
+                let n: $unsigned = read_varint(r, $expected)?;
+                #[allow(clippy::cast_possible_wrap)]
+                Ok((n >> 1) as $ty ^ -((n & 1) as $ty))
+            }
+        )*
+    };
+}
+
+impl<'i> Format<'i> for Postcard {
+    impl_read_varint! {
+        read_u16 => u16, "u16 varint";
+        read_u32 => u32, "u32 varint";
+        read_u64 => u64, "u64 varint";
+        read_u128 => u128, "u128 varint";
+        read_len => usize, "length varint";
+        read_variant => u32, "variant index varint";
+    }
+
+    impl_read_zigzag! {
+        read_i16 => i16, u16, "i16 varint";
+        read_i32 => i32, u32, "i32 varint";
+        read_i64 => i64, u64, "i64 varint";
+        read_i128 => i128, u128, "i128 varint";
+    }
+
+    fn read_bool<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<bool, E>
+    where
+        E: Error<'i>,
+    {
+        r.try_expect("bool", |r| {
+            Ok(match r.read_u8()? {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            })
+        })
+    }
+
+    fn read_i8<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<i8, E>
+    where
+        E: Error<'i>,
+    {
+        r.read_i8()
+    }
+
+    fn read_u8<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<u8, E>
+    where
+        E: Error<'i>,
+    {
+        r.read_u8()
+    }
+
+    fn read_f32<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<f32, E>
+    where
+        E: Error<'i>,
+    {
+        r.read_f32_le()
+    }
+
+    fn read_f64<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<f64, E>
+    where
+        E: Error<'i>,
+    {
+        r.read_f64_le()
+    }
+
+    fn read_char<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<char, E>
+    where
+        E: Error<'i>,
+    {
+        r.try_expect("char", |r| {
+            let mut chars = self.read_str(r)?.chars();
+            Ok(match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            })
+        })
+    }
+
+    fn read_str<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<&'i str, E>
+    where
+        E: Error<'i>,
+    {
+        let len = self.read_len(r)?;
+        r.take(len)?.to_dangerous_str()
+    }
+
+    fn read_bytes<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<&'i [u8], E>
+    where
+        E: Error<'i>,
+    {
+        let len = self.read_len(r)?;
+        r.take(len).map(|bytes| bytes.as_dangerous())
+    }
+
+    fn read_option<E>(&mut self, r: &mut BytesReader<'i, E>) -> Result<bool, E>
+    where
+        E: Error<'i>,
+    {
+        r.try_expect("option tag", |r| {
+            Ok(match r.read_u8()? {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            })
+        })
+    }
+}
+
+/// Reads a LEB128 varint no longer than needed for `T`.
+fn read_varint<'i, T, E>(r: &mut BytesReader<'i, E>, expected: &'static str) -> Result<T, E>
+where
+    T: TryFrom<u128>,
+    E: Error<'i>,
+{
+    // The bit width of an integer is never a multiple of 7.
+    let max_len = size_of::<T>() * 8 / 7 + 1;
+    r.try_expect(expected, |r| {
+        let mut value = 0_u128;
+        for i in 0..max_len {
+            let bits = u128::from(r.read_u8()?);
+            let shifted = (bits & 0x7F) << (7 * i);
+            if shifted >> (7 * i) != bits & 0x7F {
+                return Ok(None);
+            }
+            value |= shifted;
+            if bits & 0x80 == 0 {
+                return Ok(T::try_from(value).ok());
+            }
+        }
+        Ok(None)
+    })
+}
@@ -1,7 +1,10 @@
 use crate::error::{self, Context};
+#[cfg(feature = "alloc")]
+use crate::error::{Details, OwnedExpected};
 use crate::fmt::{self, Write};
-use crate::input::{Bytes, Input, Private};
+use crate::input::{Bytes, Input, MaybeString, Private};
 
+use super::input::Format;
 use super::{InputDisplay, PreferredFormat};
 
 const DEFAULT_MAX_WIDTH: usize = 80;
@@ -17,8 +20,12 @@ note: error span is not within the error input indicating the
 pub struct ErrorDisplay<'a, T> {
     error: &'a T,
     banner: bool,
-    format: Option<PreferredFormat>,
+    format: Option<Format>,
     input_max_width: usize,
+    input_format: Format,
+    input_offset: usize,
+    input_lines: usize,
+    input_len: Option<usize>,
 }
 
 impl<'a, 'i, T> ErrorDisplay<'a, T>
@@ -27,19 +34,33 @@ where
 {
     /// Create a new `ErrorDisplay` given [`error::Details`].
     pub fn new(error: &'a T) -> Self {
+        Self::with_error(error, &error.input())
+    }
+
+    /// Derive an `ErrorDisplay` from a [`fmt::Formatter`] with defaults.
+    pub fn from_formatter(error: &'a T, f: &fmt::Formatter<'_>) -> Self {
+        Self::new(error).str_hint(f.alternate())
+    }
+}
+
+impl<'a, T> ErrorDisplay<'a, T> {
+    fn with_error(error: &'a T, input: &MaybeString<'_>) -> Self {
+        let input_format = match input {
+            MaybeString::String(_) => Format::Str,
+            MaybeString::Bytes(bytes) => bytes.utf16_hint().map_or(Format::Bytes, Format::Utf16),
+        };
         Self {
             error,
             format: None,
             banner: false,
             input_max_width: DEFAULT_MAX_WIDTH,
+            input_format,
+            input_offset: 0,
+            input_lines: 0,
+            input_len: None,
         }
     }
 
-    /// Derive an `ErrorDisplay` from a [`fmt::Formatter`] with defaults.
-    pub fn from_formatter(error: &'a T, f: &fmt::Formatter<'_>) -> Self {
-        Self::new(error).str_hint(f.alternate())
-    }
-
     /// Set whether or not a banner should printed around the error.
     pub fn banner(mut self, value: bool) -> Self {
         self.banner = value;
@@ -52,36 +73,48 @@ where
         self
     }
 
-    /// Hint to the formatter that the [`crate::Input`] is a UTF-8 `str`.
-    pub fn str_hint(self, value: bool) -> Self {
-        if self.error.input().is_string() || value {
-            self.format(PreferredFormat::Str)
-        } else {
-            self.format(PreferredFormat::Bytes)
-        }
-    }
-
     /// Set the preferred way to format the [`Input`].
     pub fn format(mut self, format: PreferredFormat) -> Self {
-        self.format = Some(format);
+        self.format = Some(format.into());
         self
     }
 
-    fn write_sections(&self, w: &mut dyn Write) -> fmt::Result {
-        let input = self.error.input();
-        let span = self.error.span();
+    /// Hint to the formatter that the [`crate::Input`] is a UTF-8 `str`.
+    ///
+    /// Input known to be UTF-16 is always formatted as UTF-16 text.
+    pub fn str_hint(mut self, value: bool) -> Self {
+        self.format = match self.input_format {
+            Format::Bytes if value => Some(Format::Str),
+            format => Some(format),
+        };
+        self
+    }
+
+    fn write_error(&self, w: &mut dyn Write, error: &dyn error::Details<'_>) -> fmt::Result {
+        if self.banner {
+            w.write_str("\n-- INPUT ERROR ---------------------------------------------\n")?;
+            self.write_sections(w, error)?;
+            w.write_str("\n------------------------------------------------------------\n")
+        } else {
+            self.write_sections(w, error)
+        }
+    }
+
+    fn write_sections(&self, w: &mut dyn Write, error: &dyn error::Details<'_>) -> fmt::Result {
+        let input = error.input();
+        let span = error.span();
         // Write description
         w.write_str("error attempting to ")?;
-        w.write_str(self.error.context_stack().root().operation())?;
+        w.write_str(error.context_stack().root().operation())?;
         w.write_str(": ")?;
-        self.error.description(w)?;
+        error.description(w)?;
         w.write_char('\n')?;
         // Write inputs
         let input_display = self.configure_input_display(input.display());
         let span_display = self.configure_input_display(span.display());
-        let format = input_display.get_format();
+        let format = input_display.get_inner_format();
         let input = input.into_bytes();
-        if let Some(expected_value) = self.error.expected() {
+        if let Some(expected_value) = error.expected() {
             let expected_display = self.configure_input_display(expected_value.display());
             w.write_str("expected:\n")?;
             write_input(w, expected_display, false)?;
@@ -103,17 +136,17 @@ where
             let span_bounds = span.as_dangerous().as_ptr_range();
             let span_offset = span_bounds.start as usize - input_bounds.start as usize;
             match format {
-                PreferredFormat::Str | PreferredFormat::StrCjk | PreferredFormat::BytesAscii => {
+                Format::Str | Format::StrCjk | Format::BytesAscii => {
                     w.write_str("error line: ")?;
-                    w.write_usize(line_offset(&input, span_offset))?;
+                    w.write_usize(self.input_lines + line_offset(&input, span_offset))?;
                     w.write_str(", ")?;
                 }
                 _ => (),
             }
             w.write_str("error offset: ")?;
-            w.write_usize(span_offset)?;
+            w.write_usize(self.input_offset + span_offset)?;
             w.write_str(", input length: ")?;
-            w.write_usize(input.len())?;
+            w.write_usize(self.input_len.unwrap_or_else(|| input.len()))?;
         } else {
             w.write_str("span ptr: ")?;
             w.write_usize(span.as_dangerous().as_ptr() as usize)?;
@@ -127,7 +160,7 @@ where
         w.write_char('\n')?;
         // Write context backtrace
         w.write_str("backtrace:")?;
-        let write_success = self.error.context_stack().walk(&mut |i, c| {
+        let write_success = error.context_stack().walk(&mut |i, c| {
             let writer = |w: &mut dyn Write, i, c: &dyn Context| {
                 w.write_str("\n  ")?;
                 w.write_usize(i)?;
@@ -152,29 +185,23 @@ where
 
     fn configure_input_display<'b>(&self, display: InputDisplay<'b>) -> InputDisplay<'b> {
         if let Some(format) = self.format {
-            display.format(format)
+            display.with_format(format)
         } else {
             display
         }
     }
 }
 
-impl<'a, 'i, T> fmt::DisplayBase for ErrorDisplay<'a, T>
+impl<'i, T> fmt::DisplayBase for ErrorDisplay<'_, T>
 where
     T: error::Details<'i>,
 {
     fn fmt(&self, w: &mut dyn Write) -> fmt::Result {
-        if self.banner {
-            w.write_str("\n-- INPUT ERROR ---------------------------------------------\n")?;
-            self.write_sections(w)?;
-            w.write_str("\n------------------------------------------------------------\n")
-        } else {
-            self.write_sections(w)
-        }
+        self.write_error(w, self.error)
     }
 }
 
-impl<'a, 'i, T> fmt::Debug for ErrorDisplay<'a, T>
+impl<'i, T> fmt::Debug for ErrorDisplay<'_, T>
 where
     T: error::Details<'i>,
 {
@@ -183,7 +210,7 @@ where
     }
 }
 
-impl<'a, 'i, T> fmt::Display for ErrorDisplay<'a, T>
+impl<'i, T> fmt::Display for ErrorDisplay<'_, T>
 where
     T: error::Details<'i>,
 {
@@ -192,6 +219,43 @@ where
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Owned errors
+
+/// An [`OwnedExpected`] only retains a window of the input, so offsets are
+/// adjusted to be within the original input.
+#[cfg(feature = "alloc")]
+impl<'a> ErrorDisplay<'a, OwnedExpected> {
+    pub(crate) fn from_owned(error: &'a OwnedExpected) -> Self {
+        let mut display = Self::with_error(error, &error.input());
+        display.input_offset = error.input_offset();
+        display.input_lines = error.input_lines();
+        display.input_len = Some(error.input_len());
+        display
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::DisplayBase for ErrorDisplay<'_, OwnedExpected> {
+    fn fmt(&self, w: &mut dyn Write) -> fmt::Result {
+        self.write_error(w, &self.error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for ErrorDisplay<'_, OwnedExpected> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::DisplayBase::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ErrorDisplay<'_, OwnedExpected> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::DisplayBase::fmt(self, f)
+    }
+}
+
 fn line_offset(input: &Bytes<'_>, span_offset: usize) -> usize {
     match input.clone().split_at_opt(span_offset) {
         Some((before_span, _)) => before_span.count(b'\n') + 1,
@@ -1,5 +1,7 @@
 use crate::fmt::{self, Write};
 use crate::input::{Input, PrivateExt};
+use crate::reader::Endian;
+use crate::util::utf16;
 
 use super::section::{Section, SectionOpt};
 use super::unit::{byte_display_width, byte_display_write, char_display_width, char_display_write};
@@ -32,6 +34,30 @@ impl fmt::Debug for PreferredFormat {
     }
 }
 
+/// The format [`Input`] is written in.
+///
+/// This is a [`PreferredFormat`] or UTF-16 text, which is only selected
+/// internally for input known to be UTF-16.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Format {
+    Str,
+    StrCjk,
+    Bytes,
+    BytesAscii,
+    Utf16(Endian),
+}
+
+impl From<PreferredFormat> for Format {
+    fn from(format: PreferredFormat) -> Self {
+        match format {
+            PreferredFormat::Str => Self::Str,
+            PreferredFormat::StrCjk => Self::StrCjk,
+            PreferredFormat::Bytes => Self::Bytes,
+            PreferredFormat::BytesAscii => Self::BytesAscii,
+        }
+    }
+}
+
 /// Provides configurable [`Input`] formatting.
 ///
 /// - Defaults to formatting an [`Input`] to a max displayable width of `1024`.
@@ -62,7 +88,7 @@ impl fmt::Debug for PreferredFormat {
 pub struct InputDisplay<'i> {
     input: &'i [u8],
     underline: bool,
-    format: PreferredFormat,
+    format: Format,
     section: Option<Section<'i>>,
     section_opt: SectionOpt<'i>,
 }
@@ -72,7 +98,7 @@ impl<'i> InputDisplay<'i> {
     pub fn new(input: &impl Input<'i>) -> Self {
         Self {
             input: input.as_dangerous_bytes(),
-            format: PreferredFormat::Bytes,
+            format: Format::Bytes,
             underline: false,
             section: None,
             section_opt: DEFAULT_SECTION_OPTION,
@@ -107,7 +133,16 @@ impl<'i> InputDisplay<'i> {
     }
 
     /// Set the preferred way to format the [`Input`].
-    pub fn format(mut self, format: PreferredFormat) -> Self {
+    pub fn format(self, format: PreferredFormat) -> Self {
+        self.with_format(format.into())
+    }
+
+    /// Format the [`Input`] as UTF-16 text in the given byte order.
+    pub(crate) fn utf16(self, endian: Endian) -> Self {
+        self.with_format(Format::Utf16(endian))
+    }
+
+    pub(crate) fn with_format(mut self, format: Format) -> Self {
         self.section = None;
         self.format = format;
         self
@@ -216,13 +251,25 @@ impl<'i> InputDisplay<'i> {
     }
 
     /// Returns the preferred format.
+    ///
+    /// UTF-16 text is returned as [`PreferredFormat::Str`].
     #[must_use]
     pub fn get_format(&self) -> PreferredFormat {
+        match self.format {
+            Format::Str | Format::Utf16(_) => PreferredFormat::Str,
+            Format::StrCjk => PreferredFormat::StrCjk,
+            Format::Bytes => PreferredFormat::Bytes,
+            Format::BytesAscii => PreferredFormat::BytesAscii,
+        }
+    }
+
+    /// Returns the format the input is written in.
+    pub(crate) fn get_inner_format(&self) -> Format {
         self.format
     }
 }
 
-impl<'i> fmt::DisplayBase for InputDisplay<'i> {
+impl fmt::DisplayBase for InputDisplay<'_> {
     fn fmt(&self, w: &mut dyn Write) -> fmt::Result {
         match &self.section {
             None => self.clone().prepare().fmt(w),
@@ -231,13 +278,13 @@ impl<'i> fmt::DisplayBase for InputDisplay<'i> {
     }
 }
 
-impl<'i> fmt::Debug for InputDisplay<'i> {
+impl fmt::Debug for InputDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
     }
 }
 
-impl<'i> fmt::Display for InputDisplay<'i> {
+impl fmt::Display for InputDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
     }
@@ -261,9 +308,9 @@ impl<'a> InputWriter<'a> {
     ) -> Self {
         Self {
             w,
+            underline,
             full,
             span,
-            underline,
         }
     }
 
@@ -340,25 +387,67 @@ impl<'a> InputWriter<'a> {
     // Str
 
     pub(super) fn write_str_side(&mut self, side: &str, cjk: bool) -> fmt::Result {
+        let bytes = side.as_bytes();
+        self.write_str_open(bytes)?;
+        self.write_chars(bytes, str_chars(side), cjk)?;
+        self.write_str_close(bytes)
+    }
+
+    pub(super) fn write_str_sides(&mut self, left: &str, right: &str, cjk: bool) -> fmt::Result {
+        self.write_text_sides(
+            (left.as_bytes(), str_chars(left)),
+            (right.as_bytes(), str_chars(right)),
+            cjk,
+        )
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // UTF-16
+
+    pub(super) fn write_utf16_side(&mut self, side: &[u8], endian: Endian) -> fmt::Result {
         self.write_str_open(side)?;
-        self.write_str(side, cjk)?;
+        self.write_chars(side, utf16_chars(side, endian), false)?;
         self.write_str_close(side)
     }
 
-    pub(super) fn write_str_sides(&mut self, left: &str, right: &str, cjk: bool) -> fmt::Result {
-        self.write_str_open(left)?;
-        self.write_str(left, cjk)?;
+    pub(super) fn write_utf16_sides(
+        &mut self,
+        left: &[u8],
+        right: &[u8],
+        endian: Endian,
+    ) -> fmt::Result {
+        self.write_text_sides(
+            (left, utf16_chars(left, endian)),
+            (right, utf16_chars(right, endian)),
+            false,
+        )
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Text
+
+    fn write_text_sides<L, R>(
+        &mut self,
+        left: (&[u8], L),
+        right: (&[u8], R),
+        cjk: bool,
+    ) -> fmt::Result
+    where
+        L: Iterator<Item = (char, usize)>,
+        R: Iterator<Item = (char, usize)>,
+    {
+        self.write_str_open(left.0)?;
+        self.write_chars(left.0, left.1, cjk)?;
         self.write_delim('"', false)?;
         self.write_space(1)?;
-        self.write_more(is_span_overlapping_end(left.as_bytes(), self.span))?;
+        self.write_more(is_span_overlapping_end(left.0, self.span))?;
         self.write_space(1)?;
         self.write_delim('"', false)?;
-        self.write_str(right, cjk)?;
-        self.write_str_close(right)
+        self.write_chars(right.0, right.1, cjk)?;
+        self.write_str_close(right.0)
     }
 
-    fn write_str_open(&mut self, s: &str) -> fmt::Result {
-        let bytes = s.as_bytes();
+    fn write_str_open(&mut self, bytes: &[u8]) -> fmt::Result {
         if has_more_before(bytes, self.full) {
             self.write_more(is_span_overlapping_start(bytes, self.span))?;
             self.write_space(1)?;
@@ -368,8 +457,7 @@ impl<'a> InputWriter<'a> {
         }
     }
 
-    fn write_str_close(&mut self, s: &str) -> fmt::Result {
-        let bytes = s.as_bytes();
+    fn write_str_close(&mut self, bytes: &[u8]) -> fmt::Result {
         if has_more_after(bytes, self.full) {
             self.write_delim('"', false)?;
             self.write_space(1)?;
@@ -379,27 +467,30 @@ impl<'a> InputWriter<'a> {
         }
     }
 
-    fn write_str(&mut self, s: &str, cjk: bool) -> fmt::Result {
-        let bytes = s.as_bytes();
+    /// Writes the chars decoded from `bytes` with their byte lengths.
+    fn write_chars<I>(&mut self, bytes: &[u8], chars: I, cjk: bool) -> fmt::Result
+    where
+        I: Iterator<Item = (char, usize)>,
+    {
         if self.underline {
             if is_span_start_within_section(bytes, self.span) {
                 let mut offset = 0;
-                for c in s.chars() {
+                for (c, len) in chars {
                     let char_display_width = char_display_width(c, cjk);
                     if is_section_start_within_span(&bytes[offset..], self.span) {
                         self.write_underline(char_display_width)?;
                     } else {
                         self.write_space(char_display_width)?;
                     }
-                    offset += c.len_utf8();
+                    offset += len;
                 }
             } else {
-                for c in s.chars() {
+                for (c, _) in chars {
                     self.write_space(char_display_width(c, cjk))?;
                 }
             }
         } else {
-            for c in s.chars() {
+            for (c, _) in chars {
                 char_display_write(c, self.w)?;
             }
         }
@@ -449,6 +540,18 @@ impl<'a> InputWriter<'a> {
     }
 }
 
+fn str_chars(s: &str) -> impl Iterator<Item = (char, usize)> + '_ {
+    s.chars().map(|c| (c, c.len_utf8()))
+}
+
+fn utf16_chars(mut bytes: &[u8], endian: Endian) -> impl Iterator<Item = (char, usize)> + '_ {
+    core::iter::from_fn(move || {
+        let (c, len) = utf16::decode_front(bytes, endian)?.ok()?;
+        bytes = &bytes[len..];
+        Some((c, len))
+    })
+}
+
 fn has_more_before(bytes: &[u8], full: &[u8]) -> bool {
     let section_bounds = bytes.as_ptr_range();
     let full_bounds = full.as_ptr_range();
@@ -461,6 +564,7 @@ fn has_more_after(bytes: &[u8], full: &[u8]) -> bool {
     section_bounds.end < full_bounds.end
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_span_start_within_section(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
@@ -469,6 +573,7 @@ fn is_span_start_within_section(bytes: &[u8], span: Option<&[u8]>) -> bool {
     })
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_section_start_within_span(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
@@ -477,6 +582,7 @@ fn is_section_start_within_span(bytes: &[u8], span: Option<&[u8]>) -> bool {
     })
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_span_overlapping_end(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
@@ -485,6 +591,7 @@ fn is_span_overlapping_end(bytes: &[u8], span: Option<&[u8]>) -> bool {
     })
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_span_overlapping_start(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
@@ -493,6 +600,7 @@ fn is_span_overlapping_start(bytes: &[u8], span: Option<&[u8]>) -> bool {
     })
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_span_pointing_to_start(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
@@ -501,6 +609,7 @@ fn is_span_pointing_to_start(bytes: &[u8], span: Option<&[u8]>) -> bool {
     })
 }
 
+#[allow(clippy::unnecessary_map_or)]
 fn is_span_pointing_to_end(bytes: &[u8], span: Option<&[u8]>) -> bool {
     span.map_or(false, |span| {
         let section_bounds = bytes.as_ptr_range();
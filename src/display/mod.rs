@@ -96,7 +96,7 @@ where
     }
 }
 
-impl<'a> Write for Formatter<'a> {
+impl Write for Formatter<'_> {
     fn write_str(&mut self, s: &str) -> Result {
         core::fmt::Write::write_str(self, s)
     }
@@ -110,6 +110,23 @@ impl<'a> Write for Formatter<'a> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Write for alloc::string::String {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        self.push(c);
+        Ok(())
+    }
+
+    fn write_usize(&mut self, v: usize) -> Result {
+        core::fmt::Write::write_fmt(self, format_args!("{v}"))
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 pub(crate) fn byte_count(w: &mut dyn Write, count: usize) -> Result {
@@ -4,13 +4,16 @@
 // | tail      | `.. "a"`     | `[.. 97]`    | `[.. 'a']`     |
 // | head-tail | `"a" .. "a"` | `[97 .. 97]` | `['a' .. 'a']` |
 // | span      | `.. "a" ..`  | `[.. 97 ..]` | `[.. 'a' ..]`  |
+//
+// UTF-16 is displayed in the same way as str.
 
 use core::str;
 
 use crate::fmt::{self, Write};
-use crate::util::{slice, utf8};
+use crate::reader::Endian;
+use crate::util::{slice, utf16, utf8};
 
-use super::input::{InputWriter, PreferredFormat};
+use super::input::{Format, InputWriter};
 use super::unit::UnitIter;
 
 const MIN_WIDTH: usize = 16;
@@ -30,7 +33,7 @@ pub(super) enum SectionOpt<'a> {
 }
 
 impl<'a> SectionOpt<'a> {
-    pub(super) fn compute(self, input: &'a [u8], format: PreferredFormat) -> Section<'a> {
+    pub(super) fn compute(self, input: &'a [u8], format: Format) -> Section<'a> {
         match self {
             Self::Full => Section::from_full(input, format),
             Self::Head { width } => Section::from_head(input, width, format),
@@ -60,6 +63,10 @@ enum Visible<'a> {
     BytesPair(&'a [u8], &'a [u8]),
     // head-tail-bytes-ascii
     BytesAsciiPair(&'a [u8], &'a [u8]),
+    // head-utf16, tail-utf16, span-utf16
+    Utf16(&'a [u8], Endian),
+    // head-tail-utf16
+    Utf16Pair(&'a [u8], &'a [u8], Endian),
 }
 
 #[derive(Clone)]
@@ -70,24 +77,31 @@ pub(super) struct Section<'a> {
 }
 
 impl<'a> Section<'a> {
-    pub(super) fn from_full(full: &'a [u8], format: PreferredFormat) -> Self {
+    pub(super) fn from_full(full: &'a [u8], format: Format) -> Self {
         let visible = match format {
-            PreferredFormat::Bytes => Visible::Bytes(full),
-            PreferredFormat::BytesAscii => Visible::BytesAscii(full),
-            PreferredFormat::Str => {
+            Format::Bytes => Visible::Bytes(full),
+            Format::BytesAscii => Visible::BytesAscii(full),
+            Format::Str => {
                 if let Ok(s) = str::from_utf8(full) {
                     Visible::Str(s)
                 } else {
                     Visible::BytesAscii(full)
                 }
             }
-            PreferredFormat::StrCjk => {
+            Format::StrCjk => {
                 if let Ok(s) = str::from_utf8(full) {
                     Visible::StrCjk(s)
                 } else {
                     Visible::BytesAscii(full)
                 }
             }
+            Format::Utf16(endian) => {
+                if utf16::is_valid(full, endian) {
+                    Visible::Utf16(full, endian)
+                } else {
+                    Visible::BytesAscii(full)
+                }
+            }
         };
         Self {
             full,
@@ -96,13 +110,14 @@ impl<'a> Section<'a> {
         }
     }
 
-    pub(super) fn from_head(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_head(full: &'a [u8], width: usize, format: Format) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_head(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_head(full, width, true),
-            PreferredFormat::Str => take_str_head(full, width, false),
-            PreferredFormat::StrCjk => take_str_head(full, width, true),
+            Format::Bytes => take_bytes_head(full, width, false),
+            Format::BytesAscii => take_bytes_head(full, width, true),
+            Format::Str => take_str_head(full, width, false),
+            Format::StrCjk => take_str_head(full, width, true),
+            Format::Utf16(endian) => take_utf16_head(full, width, endian),
         };
         Self {
             full,
@@ -111,13 +126,14 @@ impl<'a> Section<'a> {
         }
     }
 
-    pub(super) fn from_tail(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_tail(full: &'a [u8], width: usize, format: Format) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_tail(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_tail(full, width, true),
-            PreferredFormat::Str => take_str_tail(full, width, false),
-            PreferredFormat::StrCjk => take_str_tail(full, width, true),
+            Format::Bytes => take_bytes_tail(full, width, false),
+            Format::BytesAscii => take_bytes_tail(full, width, true),
+            Format::Str => take_str_tail(full, width, false),
+            Format::StrCjk => take_str_tail(full, width, true),
+            Format::Utf16(endian) => take_utf16_tail(full, width, endian),
         };
         Self {
             full,
@@ -126,13 +142,14 @@ impl<'a> Section<'a> {
         }
     }
 
-    pub(super) fn from_head_tail(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_head_tail(full: &'a [u8], width: usize, format: Format) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_head_tail(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_head_tail(full, width, true),
-            PreferredFormat::Str => take_str_head_tail(full, width, false),
-            PreferredFormat::StrCjk => take_str_head_tail(full, width, true),
+            Format::Bytes => take_bytes_head_tail(full, width, false),
+            Format::BytesAscii => take_bytes_head_tail(full, width, true),
+            Format::Str => take_str_head_tail(full, width, false),
+            Format::StrCjk => take_str_head_tail(full, width, true),
+            Format::Utf16(endian) => take_utf16_head_tail(full, width, endian),
         };
         Self {
             full,
@@ -145,7 +162,7 @@ impl<'a> Section<'a> {
         full: &'a [u8],
         mut span: &'a [u8],
         width: usize,
-        format: PreferredFormat,
+        format: Format,
     ) -> Self {
         if !slice::is_sub_slice(full, span) {
             return Self::from_head_tail(full, width, format);
@@ -157,10 +174,11 @@ impl<'a> Section<'a> {
         if span.is_empty() {
             if full_bounds.start == span_bounds.start {
                 let visible = match format {
-                    PreferredFormat::Bytes => take_bytes_head(full, width, false),
-                    PreferredFormat::BytesAscii => take_bytes_head(full, width, true),
-                    PreferredFormat::Str => take_str_head(full, width, false),
-                    PreferredFormat::StrCjk => take_str_head(full, width, true),
+                    Format::Bytes => take_bytes_head(full, width, false),
+                    Format::BytesAscii => take_bytes_head(full, width, true),
+                    Format::Str => take_str_head(full, width, false),
+                    Format::StrCjk => take_str_head(full, width, true),
+                    Format::Utf16(endian) => take_utf16_head(full, width, endian),
                 };
                 return Self {
                     full,
@@ -169,10 +187,11 @@ impl<'a> Section<'a> {
                 };
             } else if full_bounds.end == span_bounds.end {
                 let visible = match format {
-                    PreferredFormat::Bytes => take_bytes_tail(full, width, false),
-                    PreferredFormat::BytesAscii => take_bytes_tail(full, width, true),
-                    PreferredFormat::Str => take_str_tail(full, width, false),
-                    PreferredFormat::StrCjk => take_str_tail(full, width, true),
+                    Format::Bytes => take_bytes_tail(full, width, false),
+                    Format::BytesAscii => take_bytes_tail(full, width, true),
+                    Format::Str => take_str_tail(full, width, false),
+                    Format::StrCjk => take_str_tail(full, width, true),
+                    Format::Utf16(endian) => take_utf16_tail(full, width, endian),
                 };
                 return Self {
                     full,
@@ -182,23 +201,31 @@ impl<'a> Section<'a> {
             }
             span = &full[span_offset..=span_offset];
         }
-        // If the span starts at an invalid UTF-8 boundary, show the section
-        // as bytes-ascii
+        // If the span starts at an invalid UTF-8 or UTF-16 boundary, show the
+        // section as bytes-ascii
         let format = match format {
-            PreferredFormat::Str | PreferredFormat::StrCjk => {
+            Format::Str | Format::StrCjk => {
                 if utf8::char_len(full[span_offset]) > 0 {
                     format
                 } else {
-                    PreferredFormat::BytesAscii
+                    Format::BytesAscii
+                }
+            }
+            Format::Utf16(endian) => {
+                if span_offset & 1 == 0 && utf16::is_boundary(&full[span_offset..], endian) {
+                    format
+                } else {
+                    Format::BytesAscii
                 }
             }
             _ => format,
         };
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_span(full, span_offset, width, false),
-            PreferredFormat::BytesAscii => take_bytes_span(full, span_offset, width, true),
-            PreferredFormat::Str => take_str_span(full, span_offset, width, false),
-            PreferredFormat::StrCjk => take_str_span(full, span_offset, width, true),
+            Format::Bytes => take_bytes_span(full, span_offset, width, false),
+            Format::BytesAscii => take_bytes_span(full, span_offset, width, true),
+            Format::Str => take_str_span(full, span_offset, width, false),
+            Format::StrCjk => take_str_span(full, span_offset, width, true),
+            Format::Utf16(endian) => take_utf16_span(full, span_offset, width, endian),
         };
         Self {
             full,
@@ -218,6 +245,10 @@ impl<'a> Section<'a> {
             Visible::BytesAsciiPair(left, right) => writer.write_bytes_sides(left, right, true),
             Visible::StrPair(left, right) => writer.write_str_sides(left, right, false),
             Visible::StrCjkPair(left, right) => writer.write_str_sides(left, right, true),
+            Visible::Utf16(bytes, endian) => writer.write_utf16_side(bytes, endian),
+            Visible::Utf16Pair(left, right, endian) => {
+                writer.write_utf16_sides(left, right, endian)
+            }
         }
     }
 }
@@ -246,6 +277,15 @@ fn take_str_span(bytes: &[u8], span_offset: usize, width: usize, cjk: bool) -> V
     }
 }
 
+fn take_utf16_span(bytes: &[u8], span_offset: usize, width: usize, endian: Endian) -> Visible<'_> {
+    let iter = UnitIter::new_utf16(bytes, endian);
+    if let Ok((start, end)) = take_span(iter, span_offset, width, false) {
+        Visible::Utf16(&bytes[start..end], endian)
+    } else {
+        take_bytes_span(bytes, span_offset, width, true)
+    }
+}
+
 fn take_bytes_span(
     bytes: &[u8],
     span_offset: usize,
@@ -276,6 +316,15 @@ fn take_str_head(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
     }
 }
 
+fn take_utf16_head(bytes: &[u8], width: usize, endian: Endian) -> Visible<'_> {
+    let iter = UnitIter::new_utf16(bytes, endian);
+    if let Ok((len, _)) = take_head(iter, width, false) {
+        Visible::Utf16(&bytes[..len], endian)
+    } else {
+        take_bytes_head(bytes, width, true)
+    }
+}
+
 fn take_bytes_head(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
     let (len, _) = take_head(iter, width, true).unwrap();
@@ -302,6 +351,15 @@ fn take_str_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
     }
 }
 
+fn take_utf16_tail(bytes: &[u8], width: usize, endian: Endian) -> Visible<'_> {
+    let iter = UnitIter::new_utf16(bytes, endian);
+    if let Ok((len, _)) = take_tail(iter, width, false) {
+        Visible::Utf16(&bytes[bytes.len() - len..], endian)
+    } else {
+        take_bytes_tail(bytes, width, true)
+    }
+}
+
 fn take_bytes_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
     let (len, _) = take_tail(iter, width, true).unwrap();
@@ -319,7 +377,7 @@ fn take_str_head_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
         // SAFETY: all chars are checked from the char iterator
         unsafe {
             if start == end {
-                let s = utf8::from_unchecked(&bytes[..]);
+                let s = utf8::from_unchecked(bytes);
                 if cjk {
                     return Visible::StrCjk(s);
                 }
@@ -336,14 +394,23 @@ fn take_str_head_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
     take_bytes_head_tail(bytes, width, true)
 }
 
+fn take_utf16_head_tail(bytes: &[u8], width: usize, endian: Endian) -> Visible<'_> {
+    let iter = UnitIter::new_utf16(bytes, endian);
+    match take_head_tail(iter, width, false, STR_HEAD_TAIL_HAS_MORE_COST) {
+        Ok((start, end)) if start == end => Visible::Utf16(bytes, endian),
+        Ok((start, end)) => Visible::Utf16Pair(&bytes[..start], &bytes[end..], endian),
+        Err(()) => take_bytes_head_tail(bytes, width, true),
+    }
+}
+
 fn take_bytes_head_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
     let (start, end) = take_head_tail(iter, width, true, HEAD_TAIL_HAS_MORE_COST).unwrap();
     if start == end {
         if show_ascii {
-            Visible::BytesAscii(&bytes[..])
+            Visible::BytesAscii(bytes)
         } else {
-            Visible::Bytes(&bytes[..])
+            Visible::Bytes(bytes)
         }
     } else {
         let left = &bytes[..start];
@@ -388,7 +455,7 @@ fn take_side(
         match unit_result {
             Ok(unit) => {
                 if calc.next(unit.display_cost, iter.has_next()) {
-                    len += unit.len_utf8
+                    len += unit.len_utf8;
                 } else {
                     break;
                 }
@@ -518,7 +585,7 @@ impl Calculator {
             self.budget -= unit_cost;
         } else {
             if has_next {
-                self.budget = self.budget.saturating_sub(self.has_more_cost)
+                self.budget = self.budget.saturating_sub(self.has_more_cost);
             }
             return false;
         }
@@ -529,10 +596,11 @@ impl Calculator {
 ///////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
+#[allow(clippy::needless_raw_string_hashes)]
 mod tests {
     use super::*;
 
-    use crate::display::InputDisplay;
+    use crate::display::{InputDisplay, PreferredFormat};
     use crate::input;
 
     const BAD_UTF8: u8 = 0b1101_1111; // 223, 0xdf
@@ -584,7 +652,7 @@ mod tests {
             display: $display:expr,
         }) => {{
             let full = $input;
-            let section = Section::$from($input, $display.len(), $format);
+            let section = Section::$from($input, $display.len(), $format.into());
             let input = InputDisplay::new(&input(&full[..]))
                 .format($format)
                 .$input_section($display.len());
@@ -603,7 +671,7 @@ mod tests {
         }) => {{
             let full = $input;
             let span = &full[$range];
-            let section = Section::from_span(full, span, $display.len(), $format);
+            let section = Section::from_span(full, span, $display.len(), $format.into());
             let input = InputDisplay::new(&input(&full[..]))
                 .format($format)
                 .span(&input(span), $display.len());
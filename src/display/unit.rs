@@ -2,6 +2,8 @@
 use unicode_width::UnicodeWidthChar;
 
 use crate::fmt::{self, Write};
+use crate::reader::Endian;
+use crate::util::utf16;
 use crate::util::utf8::CharIter;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -105,6 +107,37 @@ fn char_next_back(bytes: &mut &[u8], cjk: bool) -> Option<Result<Unit, ()>> {
     result
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// UTF-16 display
+
+fn utf16_endian(big: bool) -> Endian {
+    if big {
+        Endian::Big
+    } else {
+        Endian::Little
+    }
+}
+
+fn utf16_next_front(bytes: &mut &[u8], big: bool) -> Option<Result<Unit, ()>> {
+    match utf16::decode_front(bytes, utf16_endian(big))? {
+        Ok((c, len)) => {
+            *bytes = &bytes[len..];
+            Some(Ok(Unit::utf16(c, len)))
+        }
+        Err(_) => Some(Err(())),
+    }
+}
+
+fn utf16_next_back(bytes: &mut &[u8], big: bool) -> Option<Result<Unit, ()>> {
+    match utf16::decode_back(bytes, utf16_endian(big))? {
+        Ok((c, len)) => {
+            *bytes = &bytes[..bytes.len() - len];
+            Some(Ok(Unit::utf16(c, len)))
+        }
+        Err(()) => Some(Err(())),
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Unit iterator
 
@@ -128,6 +161,14 @@ impl Unit {
             len_utf8: c.len_utf8(),
         }
     }
+
+    /// A UTF-16 encoded char, where the length is in bytes of UTF-16.
+    pub(super) fn utf16(c: char, len: usize) -> Self {
+        Self {
+            display_cost: char_display_width(c, false),
+            len_utf8: len,
+        }
+    }
 }
 
 type UnitIterFn = fn(&mut &[u8], bool) -> Option<Result<Unit, ()>>;
@@ -159,6 +200,15 @@ impl<'a> UnitIter<'a> {
         }
     }
 
+    pub(super) fn new_utf16(bytes: &'a [u8], endian: Endian) -> Self {
+        Self {
+            bytes,
+            modifier: endian.is_big(),
+            next_front: utf16_next_front,
+            next_back: utf16_next_back,
+        }
+    }
+
     pub(super) fn has_next(&self) -> bool {
         !self.bytes.is_empty()
     }
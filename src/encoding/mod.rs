@@ -0,0 +1,100 @@
+//! Encoding detection and transcoding.
+//!
+//! Input is expected to arrive as UTF-8, but text from legacy sources may be
+//! in another encoding. [`detect_bom()`] inspects the byte order mark at the
+//! start of the input and returns it tagged with the encoding it declares.
+//! UTF-16 input can then be read with a [`Utf16Reader`](crate::Utf16Reader).
+//! UTF-32 is only detected, there is no input type to read it with.
+//!
+//! With the `alloc` feature, input in a single byte legacy [`Charset`] can be
+//! transcoded into UTF-8 with [`Transcoded`], which keeps a map of offsets
+//! back to the original bytes so errors can be displayed against them.
+
+#[cfg(feature = "alloc")]
+mod transcode;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::transcode::{Charset, Transcoded, TranscodedError};
+
+use crate::input::{Bytes, Private, Utf16};
+use crate::reader::Endian;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+const UTF16_LE_BOM: &[u8] = b"\xFF\xFE";
+const UTF16_BE_BOM: &[u8] = b"\xFE\xFF";
+const UTF32_LE_BOM: &[u8] = b"\xFF\xFE\x00\x00";
+const UTF32_BE_BOM: &[u8] = b"\x00\x00\xFE\xFF";
+
+/// Input tagged with the encoding declared by its byte order mark.
+///
+/// Returned from [`detect_bom()`], the byte order mark is removed from the
+/// input.
+#[derive(Clone, Debug, PartialEq)]
+#[must_use = "input must be consumed"]
+pub enum Detected<'i> {
+    /// UTF-8 input, declared with `EF BB BF`.
+    Utf8(Bytes<'i>),
+    /// UTF-16 input, declared with `FF FE` (LE) or `FE FF` (BE).
+    Utf16(Utf16<'i>),
+    /// UTF-32 input, declared with `FF FE 00 00` (LE) or `00 00 FE FF` (BE).
+    ///
+    /// UTF-32 is detection-only. The input is returned as [`Bytes`] with its
+    /// byte order, as there is no UTF-32 input or reader to decode it.
+    Utf32(Bytes<'i>, Endian),
+    /// Input without a recognised byte order mark, returned as is.
+    Unknown(Bytes<'i>),
+}
+
+impl<'i> Detected<'i> {
+    /// Returns the input without the byte order mark.
+    pub fn into_bytes(self) -> Bytes<'i> {
+        match self {
+            Self::Utf8(bytes) | Self::Utf32(bytes, _) | Self::Unknown(bytes) => bytes,
+            Self::Utf16(utf16) => utf16.into_bytes(),
+        }
+    }
+}
+
+/// Detects the encoding of the input from its byte order mark.
+///
+/// `FF FE 00 00` is detected as UTF-32 LE over UTF-16 LE followed by a `NUL`,
+/// as is common practice.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::Endian;
+/// use dangerous::encoding::{self, Detected};
+///
+/// let detected = encoding::detect_bom(dangerous::input(b"\xFF\xFEh\x00i\x00"));
+///
+/// match detected {
+///     Detected::Utf16(utf16) => {
+///         assert_eq!(utf16.endian(), Endian::Little);
+///         assert_eq!(utf16, *"hi");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn detect_bom(input: Bytes<'_>) -> Detected<'_> {
+    let bytes = input.as_dangerous();
+    if bytes.starts_with(UTF8_BOM) {
+        Detected::Utf8(strip_bom(input, UTF8_BOM))
+    } else if bytes.starts_with(UTF32_LE_BOM) {
+        Detected::Utf32(strip_bom(input, UTF32_LE_BOM), Endian::Little)
+    } else if bytes.starts_with(UTF32_BE_BOM) {
+        Detected::Utf32(strip_bom(input, UTF32_BE_BOM), Endian::Big)
+    } else if bytes.starts_with(UTF16_LE_BOM) {
+        Detected::Utf16(strip_bom(input, UTF16_LE_BOM).into_utf16(Endian::Little))
+    } else if bytes.starts_with(UTF16_BE_BOM) {
+        Detected::Utf16(strip_bom(input, UTF16_BE_BOM).into_utf16(Endian::Big))
+    } else {
+        Detected::Unknown(input)
+    }
+}
+
+fn strip_bom<'i>(input: Bytes<'i>, bom: &[u8]) -> Bytes<'i> {
+    // SAFETY: the input was checked to start with the BOM.
+    unsafe { input.split_at_byte_unchecked(bom.len()).1 }
+}
@@ -0,0 +1,253 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::display::ErrorDisplay;
+use crate::error::{ContextStack, Details, Value};
+use crate::fmt;
+use crate::input::{self, Bound, Bytes, Input, MaybeString};
+use crate::util::utf8;
+
+/// Windows-1252 code points for `0x80..=0x9F`, which differ from Latin-1.
+///
+/// Bytes left undefined by Windows-1252 map to the C1 control of the same
+/// value as specified by the WHATWG Encoding Standard.
+const WINDOWS_1252_C1: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// A single byte legacy character set.
+///
+/// Every byte maps to a `char` in both character sets, so transcoding can't
+/// fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Charset {
+    /// ISO-8859-1, where each byte is the code point of the same value.
+    Latin1,
+    /// Windows-1252, a superset of Latin-1 printable characters.
+    Windows1252,
+}
+
+impl Charset {
+    fn decode(self, b: u8) -> char {
+        match (self, b) {
+            (Self::Windows1252, 0x80..=0x9F) => WINDOWS_1252_C1[usize::from(b - 0x80)],
+            _ => char::from(b),
+        }
+    }
+}
+
+/// Input transcoded from a legacy [`Charset`] into UTF-8.
+///
+/// The transcoded text is read with [`Transcoded::input()`]. Errors from
+/// reading it are mapped back to the original bytes with
+/// [`Transcoded::map_error()`], so their spans and offsets are displayed
+/// relative to the input as it was received.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Expected, Input};
+/// use dangerous::encoding::{Charset, Transcoded};
+/// use dangerous::error::Details;
+///
+/// let input = dangerous::input(b"caf\xE9,\x80");
+/// let transcoded = Transcoded::new(input, Charset::Windows1252);
+/// let result: Result<_, Expected<'_>> = transcoded.input().read_all(|r| {
+///     let name = r.take_until_opt(',');
+///     r.consume(",$")?;
+///     Ok(name)
+/// });
+/// let error = transcoded.map_error(result.unwrap_err());
+///
+/// assert_eq!(transcoded.as_str(), "café,€");
+/// assert_eq!(error.span(), b",\x80"[..]);
+/// ```
+pub struct Transcoded<'o> {
+    original: Bytes<'o>,
+    text: String,
+    // The original and transcoded offsets of each non-ASCII byte.
+    offsets: Vec<(usize, usize)>,
+}
+
+impl<'o> Transcoded<'o> {
+    /// Transcodes the input from the given [`Charset`] into UTF-8.
+    #[must_use]
+    pub fn new(input: Bytes<'o>, charset: Charset) -> Self {
+        let bytes = input.as_dangerous();
+        let mut text = String::with_capacity(bytes.len());
+        let mut offsets = Vec::new();
+        for (i, &b) in bytes.iter().enumerate() {
+            if !b.is_ascii() {
+                offsets.push((i, text.len()));
+            }
+            text.push(charset.decode(b));
+        }
+        Self {
+            original: input,
+            text,
+            offsets,
+        }
+    }
+
+    /// Returns the transcoded text as [`String`](crate::String) input.
+    ///
+    /// The text is complete once transcoded so the input is bound.
+    pub fn input(&self) -> input::String<'_> {
+        input::String::new(&self.text, Bound::force_close())
+    }
+
+    /// Returns the transcoded text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the original input.
+    pub fn original(&self) -> Bytes<'o> {
+        self.original.clone()
+    }
+
+    /// Returns the range of the original input a span of the transcoded text
+    /// was transcoded from.
+    ///
+    /// A span that starts or ends within a transcoded `char` is widened to
+    /// include the original byte of that `char`.
+    ///
+    /// Returns `None` if the span is not within the transcoded text.
+    #[must_use]
+    pub fn original_range(&self, span: &[u8]) -> Option<Range<usize>> {
+        let range = Bytes::new(span, Bound::Both).span_of(&self.input())?;
+        Some(self.original_offset(range.start, false)..self.original_offset(range.end, true))
+    }
+
+    /// Maps an error from reading the transcoded text back to the original
+    /// input.
+    pub fn map_error<'t, E>(&'t self, error: E) -> TranscodedError<'t, 'o, E>
+    where
+        E: Details<'t>,
+    {
+        TranscodedError {
+            transcoded: self,
+            error,
+        }
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    fn original_offset(&self, offset: usize, round_up: bool) -> usize {
+        let i = match self.offsets.binary_search_by(|&(_, t)| t.cmp(&offset)) {
+            Ok(i) => return self.offsets[i].0,
+            Err(0) => return offset,
+            Err(i) => i - 1,
+        };
+        // The closest non-ASCII char before the offset, each byte after it in
+        // the transcoded text maps to one byte in the original.
+        let (o, t) = self.offsets[i];
+        let end = t + utf8::char_len(self.text.as_bytes()[t]);
+        if offset >= end {
+            o + 1 + (offset - end)
+        } else if round_up {
+            o + 1
+        } else {
+            o
+        }
+    }
+
+    fn original_slice(&self, span: &[u8]) -> Option<&'o [u8]> {
+        self.original_range(span)
+            .map(|range| &self.original.as_dangerous()[range])
+    }
+}
+
+impl fmt::Debug for Transcoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transcoded")
+            .field("original", &self.original)
+            .field("text", &self.input())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error from reading [`Transcoded`] text, mapped to the original input.
+///
+/// Created with [`Transcoded::map_error()`].
+#[must_use = "error must be handled"]
+pub struct TranscodedError<'t, 'o, E> {
+    transcoded: &'t Transcoded<'o>,
+    error: E,
+}
+
+impl<'t, E> TranscodedError<'t, '_, E>
+where
+    E: Details<'t>,
+{
+    /// Returns the error from reading the transcoded text.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+
+    /// Returns an `ErrorDisplay` for formatting.
+    pub fn display(&self) -> ErrorDisplay<'_, Self> {
+        ErrorDisplay::new(self)
+    }
+}
+
+impl<'t, 'o, E> Details<'o> for TranscodedError<'t, 'o, E>
+where
+    E: Details<'t>,
+{
+    fn input(&self) -> MaybeString<'o> {
+        let input = self.error.input().into_bytes();
+        match self.transcoded.original_slice(input.as_dangerous()) {
+            Some(bytes) => MaybeString::Bytes(Bytes::new(bytes, Bound::Both)),
+            None => self.transcoded.original().into_maybe_string(),
+        }
+    }
+
+    fn span(&self) -> Bytes<'o> {
+        let span = self.error.span();
+        let bytes = self
+            .transcoded
+            .original_slice(span.as_dangerous())
+            .unwrap_or_default();
+        Bytes::new(bytes, Bound::Both)
+    }
+
+    fn expected(&self) -> Option<Value<'_>> {
+        self.error.expected()
+    }
+
+    fn description(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.error.description(w)
+    }
+
+    fn context_stack(&self) -> &dyn ContextStack {
+        self.error.context_stack()
+    }
+}
+
+impl<'t, E> fmt::Debug for TranscodedError<'t, '_, E>
+where
+    E: Details<'t>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ErrorDisplay::from_formatter(self, f).banner(true).fmt(f)
+    }
+}
+
+impl<'t, E> fmt::Display for TranscodedError<'t, '_, E>
+where
+    E: Details<'t>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ErrorDisplay::from_formatter(self, f).fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'t, E> std::error::Error for TranscodedError<'t, '_, E> where E: Details<'t> {}
@@ -166,6 +166,60 @@ impl fmt::Debug for OperationContext {
 #[cfg(feature = "zc")]
 unsafe impl zc::NoInteriorMut for OperationContext {}
 
+///////////////////////////////////////////////////////////////////////////////
+// Chunk context
+
+/// The [`Context`] of a chunk an error occurred within when reading
+/// [`Chunks`].
+///
+/// Errors reading chunks are relative to the chunk they occurred within, so
+/// this context carries where that chunk starts in the logical stream.
+///
+/// [`Chunks`]: crate::Chunks
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChunkContext {
+    pub(crate) index: usize,
+    pub(crate) offset: usize,
+}
+
+impl ChunkContext {
+    /// The index of the chunk.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The offset of the start of the chunk in the logical stream.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Context for ChunkContext {
+    fn operation(&self) -> &'static str {
+        "read chunks"
+    }
+
+    fn has_expected(&self) -> bool {
+        true
+    }
+
+    fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        w.write_str("chunk ")?;
+        w.write_usize(self.index)?;
+        w.write_str(" at stream offset ")?;
+        w.write_usize(self.offset)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(feature = "zc")]
+unsafe impl zc::NoInteriorMut for ChunkContext {}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Root context stack
 
@@ -220,7 +274,7 @@ impl ContextStackBuilder for FullContextStack {
     }
 
     fn push(&mut self, context: impl Context) {
-        self.stack.push(Box::new(context))
+        self.stack.push(Box::new(context));
     }
 }
 
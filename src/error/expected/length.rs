@@ -51,7 +51,7 @@ impl<'i> ExpectedLength<'i> {
     }
 }
 
-impl<'i> fmt::Debug for ExpectedLength<'i> {
+impl fmt::Debug for ExpectedLength<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExpectedLength")
             .field("len", &self.len())
@@ -62,7 +62,7 @@ impl<'i> fmt::Debug for ExpectedLength<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for ExpectedLength<'i> {
+impl fmt::DisplayBase for ExpectedLength<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         w.write_str("found ")?;
         byte_count(w, self.span().len())?;
@@ -72,14 +72,14 @@ impl<'i> fmt::DisplayBase for ExpectedLength<'i> {
     }
 }
 
-impl<'i> fmt::Display for ExpectedLength<'i> {
+impl fmt::Display for ExpectedLength<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
     }
 }
 
 #[cfg(feature = "retry")]
-impl<'i> ToRetryRequirement for ExpectedLength<'i> {
+impl ToRetryRequirement for ExpectedLength<'_> {
     #[inline]
     fn to_retry_requirement(&self) -> Option<RetryRequirement> {
         if self.is_fatal() {
@@ -99,4 +99,4 @@ impl<'i> ToRetryRequirement for ExpectedLength<'i> {
 }
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for ExpectedLength<'i> {}
+unsafe impl zc::NoInteriorMut for ExpectedLength<'_> {}
@@ -1,8 +1,12 @@
 mod length;
+#[cfg(feature = "alloc")]
+mod owned;
 mod valid;
 mod value;
 
 pub use self::length::ExpectedLength;
+#[cfg(feature = "alloc")]
+pub use self::owned::OwnedExpected;
 pub use self::valid::ExpectedValid;
 pub use self::value::ExpectedValue;
 
@@ -31,7 +35,7 @@ type ExpectedContextStack = crate::error::RootContextStack;
 ///   stacks.
 /// - It is generally recommended for better performance to box `Expected` if
 ///   the structures being returned from parsing are smaller than or equal to
-///   `~128 bytes`. This is because the `Expected` structure is `176 - 200
+///   `~128 bytes`. This is because the `Expected` structure is `168 - 192
 ///   bytes` large on 64 bit systems and successful parses may be hindered by
 ///   the time to move the `Result<T, Expected>` value. By boxing `Expected` the
 ///   size becomes only `8 bytes`. When in doubt, write a benchmark.
@@ -53,7 +57,7 @@ enum ExpectedKind<'i> {
     Length(ExpectedLength<'i>),
 }
 
-impl<'i, S> Expected<'i, S>
+impl<S> Expected<'_, S>
 where
     S: ContextStack,
 {
@@ -61,6 +65,33 @@ where
     pub fn display(&self) -> ErrorDisplay<'_, Self> {
         ErrorDisplay::new(self)
     }
+
+    /// Converts the error into an [`OwnedExpected`] that does not borrow the
+    /// input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Expected, Input};
+    /// use dangerous::error::OwnedExpected;
+    ///
+    /// fn parse(buf: &[u8]) -> Result<u8, OwnedExpected> {
+    ///     dangerous::input(buf)
+    ///         .read_all(|r| r.read_u8())
+    ///         .map_err(|err: Expected<'_>| err.into_owned())
+    /// }
+    ///
+    /// let error = parse(&vec![1, 2]).unwrap_err();
+    /// assert_eq!(error.input_offset(), 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn into_owned(self) -> OwnedExpected {
+        let owned = OwnedExpected::from_details(&self);
+        #[cfg(feature = "retry")]
+        let owned = owned.with_retry(&self);
+        owned
+    }
 }
 
 impl<'i, S> Expected<'i, S>
@@ -70,7 +101,7 @@ where
     #[inline(always)]
     fn add_context(&mut self, input: impl Input<'i>, context: impl Context) {
         if self.input.clone().into_bytes().is_within(&input) {
-            self.input = input.into_maybe_string()
+            self.input = input.into_maybe_string();
         }
         self.stack.push(context);
     }
@@ -126,7 +157,7 @@ where
 }
 
 #[cfg(feature = "retry")]
-impl<'i, S> ToRetryRequirement for Expected<'i, S> {
+impl<S> ToRetryRequirement for Expected<'_, S> {
     fn to_retry_requirement(&self) -> Option<RetryRequirement> {
         match &self.kind {
             ExpectedKind::Value(err) => err.to_retry_requirement(),
@@ -145,7 +176,7 @@ impl<'i, S> ToRetryRequirement for Expected<'i, S> {
 }
 
 #[cfg(all(feature = "alloc", feature = "retry"))]
-impl<'i, S> ToRetryRequirement for Box<Expected<'i, S>> {
+impl<S> ToRetryRequirement for Box<Expected<'_, S>> {
     fn to_retry_requirement(&self) -> Option<RetryRequirement> {
         (**self).to_retry_requirement()
     }
@@ -176,7 +207,7 @@ where
     }
 }
 
-impl<'i, S> fmt::Debug for Expected<'i, S>
+impl<S> fmt::Debug for Expected<'_, S>
 where
     S: ContextStack,
 {
@@ -185,7 +216,7 @@ where
     }
 }
 
-impl<'i, S> fmt::Display for Expected<'i, S>
+impl<S> fmt::Display for Expected<'_, S>
 where
     S: ContextStack,
 {
@@ -251,11 +282,21 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'i, S> From<Expected<'i, S>> for OwnedExpected
+where
+    S: ContextStack,
+{
+    fn from(err: Expected<'i, S>) -> Self {
+        err.into_owned()
+    }
+}
+
 #[cfg(feature = "std")]
-impl<'i, S> std::error::Error for Expected<'i, S> where S: ContextStack {}
+impl<S> std::error::Error for Expected<'_, S> where S: ContextStack {}
 
 #[cfg(feature = "zc")]
-unsafe impl<'i, S> zc::NoInteriorMut for Expected<'i, S> where S: zc::NoInteriorMut {}
+unsafe impl<S> zc::NoInteriorMut for Expected<'_, S> where S: zc::NoInteriorMut {}
 
 #[cfg(test)]
 mod tests {
@@ -265,13 +306,13 @@ mod tests {
     #[cfg(all(target_pointer_width = "64", not(feature = "full-context")))]
     fn test_expected_size() {
         // Update the docs if this value changes.
-        assert_eq!(core::mem::size_of::<Expected<'_>>(), 176);
+        assert_eq!(core::mem::size_of::<Expected<'_>>(), 168);
     }
 
     #[test]
     #[cfg(all(target_pointer_width = "64", feature = "full-context"))]
     fn test_expected_size() {
         // Update the docs if this value changes.
-        assert_eq!(core::mem::size_of::<Expected<'_>>(), 200);
+        assert_eq!(core::mem::size_of::<Expected<'_>>(), 192);
     }
 }
@@ -0,0 +1,307 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::ops::Range;
+
+use crate::display::ErrorDisplay;
+use crate::fmt;
+use crate::input::{Bound, Bytes, Input, MaybeString};
+use crate::reader::Endian;
+
+use super::super::{Context, ContextStack, ContextStackWalker, Details, ExpectedContext, Value};
+
+#[cfg(feature = "retry")]
+use super::super::{RetryRequirement, ToRetryRequirement};
+
+/// The number of bytes of input copied either side of the error span.
+const CONTEXT_LEN: usize = 256;
+/// The maximum number of bytes of the error span copied.
+const SPAN_MAX_LEN: usize = 1024;
+
+/// An owned [`Expected`] error that does not borrow the input.
+///
+/// Created with [`Expected::into_owned()`], it copies a bounded window of the
+/// input around the error span along with the description, expected value and
+/// context stack. The error can then escape the lifetime of the input and be
+/// sent across threads.
+///
+/// The window of input copied is at most `256` bytes either side of the error
+/// span, with the span itself truncated to `1024` bytes. The offset of the
+/// window within the original input is returned by
+/// [`OwnedExpected::input_offset()`], and the offsets, lines and input length
+/// displayed are adjusted to be those of the original input.
+///
+/// [`Expected`]: crate::Expected
+/// [`Expected::into_owned()`]: crate::Expected::into_owned()
+#[must_use = "error must be handled"]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct OwnedExpected {
+    input: Vec<u8>,
+    input_is_str: bool,
+    input_utf16: Option<Endian>,
+    input_offset: usize,
+    input_lines: usize,
+    input_len: usize,
+    span: Range<usize>,
+    expected: Option<(Vec<u8>, bool)>,
+    description: String,
+    stack: OwnedContextStack,
+    #[cfg(feature = "retry")]
+    retry_requirement: Option<RetryRequirement>,
+    #[cfg(feature = "retry")]
+    fatal: bool,
+}
+
+impl OwnedExpected {
+    pub(crate) fn from_details<'i, T>(error: &T) -> Self
+    where
+        T: Details<'i> + ?Sized,
+    {
+        let input = error.input();
+        let span = error.span();
+        let mut input_is_str = input.is_string();
+        let input = input.into_bytes();
+        let mut input_utf16 = input.utf16_hint();
+        // If the span is not within the input the concrete error has a bug, we
+        // use the span as the input so it is still retained.
+        let (bytes, span_range) = if let Some(range) = span.span_of(&input) {
+            (input.as_dangerous(), range)
+        } else {
+            input_is_str = false;
+            input_utf16 = None;
+            (span.as_dangerous(), 0..span.len())
+        };
+        let mut span_end = span_range.end.min(span_range.start + SPAN_MAX_LEN);
+        let mut start = span_range.start.saturating_sub(CONTEXT_LEN);
+        let mut end = bytes.len().min(span_end + CONTEXT_LEN);
+        if input_is_str {
+            while start < span_range.start && !is_char_boundary(bytes, start) {
+                start += 1;
+            }
+            while end > span_end && !is_char_boundary(bytes, end) {
+                end -= 1;
+            }
+            while span_end > span_range.start && !is_char_boundary(bytes, span_end) {
+                span_end -= 1;
+            }
+            input_is_str = core::str::from_utf8(&bytes[start..end]).is_ok();
+        }
+        let mut description = String::new();
+        // Writing to a `String` can't fail.
+        let _ = error.description(&mut description);
+        Self {
+            input: bytes[start..end].to_vec(),
+            input_is_str,
+            input_utf16,
+            input_offset: start,
+            input_lines: Bytes::new(&bytes[..start], Bound::Both).count(b'\n'),
+            input_len: bytes.len(),
+            span: (span_range.start - start)..(span_end - start),
+            expected: error
+                .expected()
+                .map(|value| (value.as_bytes().to_vec(), value.is_str())),
+            description,
+            stack: OwnedContextStack::from_stack(error.context_stack()),
+            #[cfg(feature = "retry")]
+            retry_requirement: None,
+            #[cfg(feature = "retry")]
+            fatal: true,
+        }
+    }
+
+    #[cfg(feature = "retry")]
+    pub(crate) fn with_retry<T>(mut self, error: &T) -> Self
+    where
+        T: ToRetryRequirement,
+    {
+        self.retry_requirement = error.to_retry_requirement();
+        self.fatal = error.is_fatal();
+        self
+    }
+
+    /// Returns the byte offset of the copied input window within the original
+    /// input.
+    #[must_use]
+    pub fn input_offset(&self) -> usize {
+        self.input_offset
+    }
+
+    /// Returns the number of lines in the original input before the copied
+    /// input window.
+    pub(crate) fn input_lines(&self) -> usize {
+        self.input_lines
+    }
+
+    /// Returns the length of the original input.
+    pub(crate) fn input_len(&self) -> usize {
+        self.input_len
+    }
+
+    /// Returns an `ErrorDisplay` for formatting.
+    pub fn display(&self) -> ErrorDisplay<'_, Self> {
+        ErrorDisplay::from_owned(self)
+    }
+}
+
+fn is_char_boundary(bytes: &[u8], index: usize) -> bool {
+    // Continuation bytes are of the form `0b10xx_xxxx`.
+    !matches!(bytes.get(index), Some(&b) if (b & 0xC0) == 0x80)
+}
+
+impl<'a> Details<'a> for &'a OwnedExpected {
+    fn input(&self) -> MaybeString<'a> {
+        let input = &self.input[..];
+        if self.input_is_str {
+            // SAFETY: the input window was validated as UTF-8 when copied.
+            let s = unsafe { core::str::from_utf8_unchecked(input) };
+            MaybeString::String(crate::input::String::new(s, Bound::Both))
+        } else {
+            let bytes = Bytes::new(input, Bound::Both);
+            MaybeString::Bytes(match self.input_utf16 {
+                Some(endian) => bytes.with_utf16_hint(endian),
+                None => bytes,
+            })
+        }
+    }
+
+    fn span(&self) -> Bytes<'a> {
+        let input = &self.input[..];
+        Bytes::new(&input[self.span.clone()], Bound::Both)
+    }
+
+    fn expected(&self) -> Option<Value<'_>> {
+        self.expected
+            .as_ref()
+            .map(|(bytes, is_str)| match core::str::from_utf8(bytes) {
+                Ok(s) if *is_str => Value::from(s),
+                _ => Value::from(bytes.as_slice()),
+            })
+    }
+
+    fn description(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        w.write_str(&self.description)
+    }
+
+    fn context_stack(&self) -> &dyn ContextStack {
+        &self.stack
+    }
+}
+
+#[cfg(feature = "retry")]
+impl ToRetryRequirement for OwnedExpected {
+    fn to_retry_requirement(&self) -> Option<RetryRequirement> {
+        self.retry_requirement
+    }
+
+    fn is_fatal(&self) -> bool {
+        self.fatal
+    }
+}
+
+impl fmt::Debug for OwnedExpected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ErrorDisplay::from_owned(self)
+            .str_hint(f.alternate())
+            .banner(true)
+            .fmt(f)
+    }
+}
+
+impl fmt::Display for OwnedExpected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ErrorDisplay::from_owned(self)
+            .str_hint(f.alternate())
+            .fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OwnedExpected {}
+
+///////////////////////////////////////////////////////////////////////////////
+// Owned context stack
+
+/// A [`ContextStack`] with each [`Context`] rendered and copied.
+struct OwnedContextStack {
+    root: ExpectedContext,
+    stack: Vec<OwnedContext>,
+}
+
+impl OwnedContextStack {
+    fn from_stack(stack: &dyn ContextStack) -> Self {
+        let mut contexts = Vec::with_capacity(stack.count());
+        stack.walk(&mut |_, context| {
+            contexts.push(OwnedContext::from_context(context));
+            true
+        });
+        // The last context walked is the root which we keep as is.
+        contexts.pop();
+        contexts.reverse();
+        Self {
+            root: stack.root(),
+            stack: contexts,
+        }
+    }
+}
+
+impl ContextStack for OwnedContextStack {
+    fn root(&self) -> ExpectedContext {
+        self.root
+    }
+
+    fn count(&self) -> usize {
+        self.stack.len() + 1
+    }
+
+    fn walk<'a>(&'a self, f: &mut ContextStackWalker<'a>) -> bool {
+        let mut i = 1;
+        for item in self.stack.iter().rev() {
+            if !f(i, item) {
+                return false;
+            }
+            i += 1;
+        }
+        f(i, &self.root)
+    }
+}
+
+struct OwnedContext {
+    operation: &'static str,
+    expected: Option<String>,
+}
+
+impl OwnedContext {
+    fn from_context(context: &dyn Context) -> Self {
+        let expected = if context.has_expected() {
+            let mut expected = String::new();
+            context.expected(&mut expected).ok().map(|()| expected)
+        } else {
+            None
+        };
+        Self {
+            operation: context.operation(),
+            expected,
+        }
+    }
+}
+
+impl Context for OwnedContext {
+    fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    fn has_expected(&self) -> bool {
+        self.expected.is_some()
+    }
+
+    fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        match &self.expected {
+            Some(expected) => w.write_str(expected),
+            None => Err(fmt::Error),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
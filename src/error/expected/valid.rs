@@ -47,7 +47,7 @@ impl<'i> ExpectedValid<'i> {
     }
 }
 
-impl<'i> fmt::Debug for ExpectedValid<'i> {
+impl fmt::Debug for ExpectedValid<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_struct("ExpectedValid");
 
@@ -62,21 +62,21 @@ impl<'i> fmt::Debug for ExpectedValid<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for ExpectedValid<'i> {
+impl fmt::DisplayBase for ExpectedValid<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         w.write_str("expected ")?;
         w.write_str(self.context.expected)
     }
 }
 
-impl<'i> fmt::Display for ExpectedValid<'i> {
+impl fmt::Display for ExpectedValid<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
     }
 }
 
 #[cfg(feature = "retry")]
-impl<'i> ToRetryRequirement for ExpectedValid<'i> {
+impl ToRetryRequirement for ExpectedValid<'_> {
     #[inline]
     fn to_retry_requirement(&self) -> Option<RetryRequirement> {
         if self.is_fatal() {
@@ -93,4 +93,4 @@ impl<'i> ToRetryRequirement for ExpectedValid<'i> {
 }
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for ExpectedValid<'i> {}
+unsafe impl zc::NoInteriorMut for ExpectedValid<'_> {}
@@ -44,7 +44,7 @@ impl<'i> ExpectedValue<'i> {
     }
 }
 
-impl<'i> fmt::Debug for ExpectedValue<'i> {
+impl fmt::Debug for ExpectedValue<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExpectedValue")
             .field("input", &self.input())
@@ -55,20 +55,20 @@ impl<'i> fmt::Debug for ExpectedValue<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for ExpectedValue<'i> {
+impl fmt::DisplayBase for ExpectedValue<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         w.write_str("found a different value to the exact expected")
     }
 }
 
-impl<'i> fmt::Display for ExpectedValue<'i> {
+impl fmt::Display for ExpectedValue<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
     }
 }
 
 #[cfg(feature = "retry")]
-impl<'i> ToRetryRequirement for ExpectedValue<'i> {
+impl ToRetryRequirement for ExpectedValue<'_> {
     #[inline]
     fn to_retry_requirement(&self) -> Option<RetryRequirement> {
         if self.is_fatal() {
@@ -93,4 +93,4 @@ impl<'i> ToRetryRequirement for ExpectedValue<'i> {
 }
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for ExpectedValue<'i> {}
+unsafe impl zc::NoInteriorMut for ExpectedValue<'_> {}
@@ -34,9 +34,12 @@ mod value;
 #[cfg_attr(docsrs, doc(cfg(feature = "full-context")))]
 pub use self::context::FullContextStack;
 pub use self::context::{
-    Context, ContextStack, ContextStackBuilder, ContextStackWalker, ExpectedContext,
+    ChunkContext, Context, ContextStack, ContextStackBuilder, ContextStackWalker, ExpectedContext,
     RootContextStack,
 };
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::expected::OwnedExpected;
 pub use self::expected::{Expected, ExpectedLength, ExpectedValid, ExpectedValue};
 pub use self::fatal::Fatal;
 #[cfg(feature = "retry")]
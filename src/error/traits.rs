@@ -13,6 +13,7 @@ pub trait WithContext<'i> {
     /// Return `Self` with context.
     ///
     /// This method is used for adding parent contexts to errors bubbling up.
+    #[must_use]
     fn with_context(self, input: impl Input<'i>, context: impl Context) -> Self;
 }
 
@@ -3,6 +3,8 @@ use core::slice;
 use crate::display::InputDisplay;
 use crate::fmt;
 use crate::input::{Bound, Bytes, Input};
+use crate::reader::Endian;
+use crate::util::utf16;
 use crate::util::utf8::CharBytes;
 
 /// Value that was expected in an operation.
@@ -16,9 +18,32 @@ enum ValueInner<'i> {
     Char(CharBytes),
     Bytes(&'i [u8]),
     String(&'i str),
+    Uint([u8; 8], u8),
+    Utf16([u8; 4], u8, Endian),
 }
 
-impl<'i> Value<'i> {
+impl Value<'_> {
+    /// Creates a value from an unsigned integer encoded with `width` bytes in
+    /// the given byte order.
+    pub(crate) fn from_uint(value: u64, width: usize, endian: Endian) -> Self {
+        const WIDTH: usize = core::mem::size_of::<u64>();
+        let width = width.min(WIDTH);
+        let mut buf = [0; WIDTH];
+        match endian {
+            Endian::Big => buf[..width].copy_from_slice(&value.to_be_bytes()[WIDTH - width..]),
+            Endian::Little => buf[..width].copy_from_slice(&value.to_le_bytes()[..width]),
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Self(ValueInner::Uint(buf, width as u8))
+    }
+
+    /// Creates a value from a char encoded as UTF-16 in the given byte order.
+    pub(crate) fn from_utf16_char(c: char, endian: Endian) -> Self {
+        let (buf, len) = utf16::encode_char(c, endian);
+        #[allow(clippy::cast_possible_truncation)]
+        Self(ValueInner::Utf16(buf, len as u8, endian))
+    }
+
     /// Returns the value as bytes.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
@@ -27,39 +52,50 @@ impl<'i> Value<'i> {
             ValueInner::Char(v) => v.as_bytes(),
             ValueInner::Bytes(v) => v,
             ValueInner::String(v) => v.as_bytes(),
+            ValueInner::Uint(v, len) => &v[..*len as usize],
+            ValueInner::Utf16(v, len, _) => &v[..*len as usize],
         }
     }
 
+    /// Returns `true` if the value is known to be UTF-8.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_str(&self) -> bool {
+        matches!(self.0, ValueInner::Char(_) | ValueInner::String(_))
+    }
+
     /// Returns an [`InputDisplay`] for formatting.
     pub fn display(&self) -> InputDisplay<'_> {
         let display = Bytes::new(self.as_bytes(), Bound::Both).display();
         match self.0 {
-            ValueInner::Byte(_) | ValueInner::Bytes(_) => display,
+            ValueInner::Byte(_) | ValueInner::Bytes(_) | ValueInner::Uint(..) => display,
             ValueInner::Char(_) | ValueInner::String(_) => display.str_hint(true),
+            ValueInner::Utf16(_, _, endian) => display.utf16(endian),
         }
     }
 }
 
-impl<'i> fmt::Debug for Value<'i> {
+impl fmt::Debug for Value<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self.0 {
             ValueInner::Byte(_) => "Byte",
             ValueInner::Char(_) => "Char",
             ValueInner::Bytes(_) => "Bytes",
             ValueInner::String(_) => "String",
+            ValueInner::Uint(..) => "Uint",
+            ValueInner::Utf16(..) => "Utf16",
         };
         let display = self.display().str_hint(f.alternate());
         f.debug_tuple(name).field(&display).finish()
     }
 }
 
-impl<'i> From<u8> for Value<'i> {
+impl From<u8> for Value<'_> {
     fn from(v: u8) -> Self {
         Self(ValueInner::Byte(v))
     }
 }
 
-impl<'i> From<char> for Value<'i> {
+impl From<char> for Value<'_> {
     fn from(v: char) -> Self {
         Self(ValueInner::Char(v.into()))
     }
@@ -2,15 +2,17 @@ use core::convert::TryInto;
 use core::slice::Iter as SliceIter;
 use core::{iter, str};
 
+use crate::checksum::Checksum;
 use crate::display::InputDisplay;
 use crate::error::{
-    with_context, ExpectedContext, ExpectedLength, ExpectedValid, Length, OperationContext,
-    WithContext,
+    with_context, ExpectedContext, ExpectedLength, ExpectedValid, ExpectedValue, Length,
+    OperationContext, Value, WithContext,
 };
 use crate::fmt;
+use crate::reader::Endian;
 use crate::util::{slice, utf8};
 
-use super::{Bound, Input, MaybeString, Private, PrivateExt, String};
+use super::{Bound, Input, Lines, MaybeString, Private, PrivateExt, String, Utf16};
 
 /// Raw [`Input`].
 #[derive(Clone)]
@@ -19,19 +21,25 @@ pub struct Bytes<'i> {
     value: &'i [u8],
     #[cfg(feature = "retry")]
     bound: Bound,
+    /// Display hint set when the bytes are UTF-16 text.
+    utf16: Option<Endian>,
 }
 
 impl<'i> Bytes<'i> {
     #[cfg(feature = "retry")]
     #[inline(always)]
     pub(crate) fn new(value: &'i [u8], bound: Bound) -> Self {
-        Self { value, bound }
+        Self {
+            value,
+            bound,
+            utf16: None,
+        }
     }
 
     #[cfg(not(feature = "retry"))]
     #[inline(always)]
     pub(crate) fn new(value: &'i [u8], _bound: Bound) -> Self {
-        Self { value }
+        Self { value, utf16: None }
     }
 
     /// Returns the underlying byte slice length.
@@ -68,6 +76,26 @@ impl<'i> Bytes<'i> {
         }
     }
 
+    /// Returns `true` if the underlying byte slice is equal to `other`,
+    /// without exiting early on the first byte that differs.
+    ///
+    /// Unlike `==`, the comparison is intended for secrets such as MAC tags or
+    /// tokens. This is best effort and not a guarantee of constant time
+    /// execution. The lengths are always compared in variable time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let input = dangerous::input(b"secret");
+    ///
+    /// assert!(input.ct_eq(b"secret"));
+    /// assert!(!input.ct_eq(b"public"));
+    /// ```
+    #[must_use]
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        slice::ct_eq(self.as_dangerous(), other)
+    }
+
     /// Returns the underlying byte slice.
     ///
     /// The naming of this function is to a degree hyperbole, and should not be
@@ -100,6 +128,16 @@ impl<'i> Bytes<'i> {
         })
     }
 
+    /// Returns the input as [`Utf16`] encoded with the given byte order.
+    ///
+    /// The input is not validated, see [`Utf16Reader`] for reading it.
+    ///
+    /// [`Utf16Reader`]: crate::Utf16Reader
+    #[inline(always)]
+    pub fn into_utf16(self, endian: Endian) -> Utf16<'i> {
+        Utf16::new(self.value, endian, self.bound())
+    }
+
     /// Returns the underlying byte slice if it is not empty.
     ///
     /// See [`Bytes::as_dangerous`] for naming.
@@ -169,6 +207,65 @@ impl<'i> Bytes<'i> {
         self.to_dangerous_str()
             .map(|s| String::new(s, self.bound()))
     }
+
+    /// Returns an iterator over the lines of the input.
+    ///
+    /// Lines are split on `\n` with a trailing `\r` removed. A trailing empty
+    /// line is not yielded. Each complete line is bound at its end while the
+    /// last line carries the bound of `self`, so a partial line of unbound
+    /// input can be distinguished from a complete one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let mut lines = dangerous::input(b"one\r\ntwo\nthree").lines();
+    ///
+    /// assert_eq!(lines.next().unwrap(), b"one"[..]);
+    /// assert_eq!(lines.next().unwrap(), b"two"[..]);
+    ///
+    /// let last = lines.next().unwrap();
+    /// assert_eq!(last, b"three"[..]);
+    /// assert!(!last.is_bound());
+    /// assert!(lines.next().is_none());
+    /// ```
+    #[inline]
+    pub fn lines(self) -> Lines<'i, Self> {
+        Lines::new(self)
+    }
+
+    /// Returns the input as a [`bytes::Bytes`] sharing the buffer of `buf`,
+    /// without copying, and `None` if the input is not within `buf`.
+    ///
+    /// This is the equivalent of [`bytes::Bytes::slice_ref()`] that won't
+    /// panic, for input read from a `bytes::Bytes` with
+    /// [`dangerous::input()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let buf = bytes::Bytes::from_static(b"GET /index.html");
+    /// let result: Result<_, Invalid> = dangerous::input(&buf).read_all(|r| {
+    ///     r.consume(b"GET ")?;
+    ///     Ok(r.take_remaining())
+    /// });
+    /// let path = result.unwrap().slice_ref_of(&buf).unwrap();
+    ///
+    /// assert_eq!(path, "/index.html");
+    /// assert_eq!(path.as_ptr(), buf[4..].as_ptr());
+    /// ```
+    ///
+    /// [`dangerous::input()`]: crate::input()
+    #[must_use]
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    pub fn slice_ref_of(&self, buf: &bytes::Bytes) -> Option<bytes::Bytes> {
+        self.span_of(&Bytes::new(buf, Bound::Start))
+            .map(|range| buf.slice(range))
+    }
 }
 
 impl<'i> Input<'i> for Bytes<'i> {
@@ -209,13 +306,30 @@ impl<'i> Input<'i> for Bytes<'i> {
 
     #[inline(always)]
     fn display(&self) -> InputDisplay<'i> {
-        InputDisplay::new(self)
+        match self.utf16 {
+            Some(endian) => InputDisplay::new(self).utf16(endian),
+            None => InputDisplay::new(self),
+        }
     }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 
 impl<'i> Bytes<'i> {
+    /// Hints the bytes are UTF-16 text in the given byte order when
+    /// displayed.
+    #[inline(always)]
+    pub(crate) fn with_utf16_hint(mut self, endian: Endian) -> Self {
+        self.utf16 = Some(endian);
+        self
+    }
+
+    /// Returns the byte order if the bytes are hinted as UTF-16 text.
+    #[inline(always)]
+    pub(crate) fn utf16_hint(&self) -> Option<Endian> {
+        self.utf16
+    }
+
     #[inline(always)]
     pub(crate) fn split_str_while<F, E>(
         self,
@@ -242,14 +356,14 @@ impl<'i> Bytes<'i> {
                     // This means we know the head input has a bound.
                     let head = String::new(consumed, self.bound().close_end());
                     // For the tail we derive the bound constaint from self.
-                    let tail = Bytes::new(&bytes[consumed.as_bytes().len()..], self.bound());
+                    let tail = Bytes::new(&bytes[consumed.len()..], self.bound());
                     // Return the split input parts.
                     return Ok((head, tail));
                 }
                 Err(utf8_err) => {
                     return Err(self.map_utf8_error(
                         utf8_err.error_len(),
-                        consumed.as_bytes().len(),
+                        consumed.len(),
                         operation,
                     ))
                 }
@@ -286,7 +400,7 @@ impl<'i> Bytes<'i> {
                         // This means we know the head input has a bound.
                         let head = String::new(consumed, self.bound().close_end());
                         // For the tail we derive the bound constaint from self.
-                        let tail = Bytes::new(&bytes[consumed.as_bytes().len()..], self.bound());
+                        let tail = Bytes::new(&bytes[consumed.len()..], self.bound());
                         // Return the split input parts.
                         return Ok((head, tail));
                     }
@@ -294,7 +408,7 @@ impl<'i> Bytes<'i> {
                 Err(utf8_err) => {
                     return Err(self.map_utf8_error(
                         utf8_err.error_len(),
-                        consumed.as_bytes().len(),
+                        consumed.len(),
                         operation,
                     ))
                 }
@@ -317,6 +431,17 @@ impl<'i> Bytes<'i> {
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn split_arr_3<E>(self, operation: &'static str) -> Result<([u8; 3], Bytes<'i>), E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        match self.split_at(3, operation) {
+            Ok((head, tail)) => Ok((head.as_dangerous().try_into().unwrap(), tail)),
+            Err(err) => Err(err),
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn split_arr_4<E>(self, operation: &'static str) -> Result<([u8; 4], Bytes<'i>), E>
     where
@@ -328,6 +453,17 @@ impl<'i> Bytes<'i> {
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn split_arr_6<E>(self, operation: &'static str) -> Result<([u8; 6], Bytes<'i>), E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        match self.split_at(6, operation) {
+            Ok((head, tail)) => Ok((head.as_dangerous().try_into().unwrap(), tail)),
+            Err(err) => Err(err),
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn split_arr_8<E>(self, operation: &'static str) -> Result<([u8; 8], Bytes<'i>), E>
     where
@@ -350,6 +486,160 @@ impl<'i> Bytes<'i> {
         }
     }
 
+    /// Splits an unsigned integer `len` bytes long from the input.
+    ///
+    /// Any bytes beyond the width of a `u64` must be zero.
+    #[inline(always)]
+    pub(crate) fn split_uint<E>(
+        self,
+        len: usize,
+        endian: Endian,
+        operation: &'static str,
+    ) -> Result<(u64, Bytes<'i>), E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        const WIDTH: usize = core::mem::size_of::<u64>();
+        let (head, tail) = self.clone().split_at::<E>(len, operation)?;
+        let bytes = head.as_dangerous();
+        let (excess, value) = match endian {
+            Endian::Big => slice::split_at_opt(bytes, len.saturating_sub(WIDTH)),
+            Endian::Little => {
+                slice::split_at_opt(bytes, len.min(WIDTH)).map(|(value, excess)| (excess, value))
+            }
+        }
+        .unwrap_or((&[], bytes));
+        if excess.iter().any(|b| *b != 0) {
+            return Err(E::from(ExpectedValid {
+                span: bytes,
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "u64 sized unsigned integer",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            }));
+        }
+        let mut buf = [0; WIDTH];
+        let number = match endian {
+            Endian::Big => {
+                buf[WIDTH - value.len()..].copy_from_slice(value);
+                u64::from_be_bytes(buf)
+            }
+            Endian::Little => {
+                buf[..value.len()].copy_from_slice(value);
+                u64::from_le_bytes(buf)
+            }
+        };
+        Ok((number, tail))
+    }
+
+    /// Splits a slice of `count` plain-old-data values from the input without
+    /// copying.
+    ///
+    /// Misaligned input is an error, there is no copying fallback.
+    #[cfg(feature = "bytemuck")]
+    #[inline(always)]
+    pub(crate) fn split_pod_slice<T, E>(
+        self,
+        count: usize,
+        operation: &'static str,
+    ) -> Result<(&'i [T], Bytes<'i>), E>
+    where
+        T: bytemuck::Pod,
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        let len = core::mem::size_of::<T>().saturating_mul(count);
+        let (head, tail) = self.clone().split_at::<E>(len, operation)?;
+        match bytemuck::try_cast_slice(head.as_dangerous()) {
+            Ok(values) => Ok((values, tail)),
+            Err(err) => {
+                let expected = match err {
+                    bytemuck::PodCastError::TargetAlignmentGreaterAndInputNotAligned => {
+                        "aligned value"
+                    }
+                    _ => "value of a non-zero size",
+                };
+                Err(E::from(ExpectedValid {
+                    span: head.as_dangerous(),
+                    input: self.into_maybe_string(),
+                    context: ExpectedContext {
+                        operation,
+                        expected,
+                    },
+                    #[cfg(feature = "retry")]
+                    retry_requirement: None,
+                }))
+            }
+        }
+    }
+
+    /// Splits a prefix from the input without exiting early on the first byte
+    /// that differs.
+    ///
+    /// On a mismatch the error spans the whole prefix length so the position
+    /// of the first differing byte is not exposed.
+    #[inline(always)]
+    pub(crate) fn split_prefix_ct<E>(
+        self,
+        prefix: &[u8],
+        operation: &'static str,
+    ) -> Result<(Bytes<'i>, Bytes<'i>), E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        let (head, tail) = self.clone().split_at::<E>(prefix.len(), operation)?;
+        if head.ct_eq(prefix) {
+            Ok((head, tail))
+        } else {
+            Err(E::from(ExpectedValid {
+                span: head.as_dangerous(),
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "matching secret",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            }))
+        }
+    }
+
+    /// Splits a checksum from the input, verifying it matches the expected.
+    #[inline(always)]
+    pub(crate) fn split_checksum<C, E>(
+        self,
+        checksum: &C,
+        endian: Endian,
+        operation: &'static str,
+    ) -> Result<((), Bytes<'i>), E>
+    where
+        C: Checksum,
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValue<'i>>,
+    {
+        let width = checksum.width();
+        let (found, tail) = self.clone().split_at::<E>(width, operation)?;
+        let expected = Value::from_uint(checksum.finish(), width, endian);
+        if found == expected.as_bytes() {
+            Ok(((), tail))
+        } else {
+            Err(E::from(ExpectedValue {
+                actual: found.as_dangerous(),
+                expected,
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "matching checksum",
+                },
+            }))
+        }
+    }
+
     fn map_utf8_error<E>(
         self,
         error_len: Option<usize>,
@@ -447,28 +737,28 @@ impl<'i> Private<'i> for Bytes<'i> {
 ///////////////////////////////////////////////////////////////////////////////
 // Equality
 
-impl<'i> PartialEq for Bytes<'i> {
+impl PartialEq for Bytes<'_> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.as_dangerous() == other.as_dangerous()
     }
 }
 
-impl<'i> PartialEq<[u8]> for Bytes<'i> {
+impl PartialEq<[u8]> for Bytes<'_> {
     #[inline(always)]
     fn eq(&self, other: &[u8]) -> bool {
         self.as_dangerous() == other
     }
 }
 
-impl<'i> PartialEq<[u8]> for &Bytes<'i> {
+impl PartialEq<[u8]> for &Bytes<'_> {
     #[inline(always)]
     fn eq(&self, other: &[u8]) -> bool {
         self.as_dangerous() == other
     }
 }
 
-impl<'i> PartialEq<&[u8]> for Bytes<'i> {
+impl PartialEq<&[u8]> for Bytes<'_> {
     #[inline(always)]
     fn eq(&self, other: &&[u8]) -> bool {
         self.as_dangerous() == *other
@@ -485,7 +775,7 @@ impl<'i> PartialEq<Bytes<'i>> for [u8] {
 ///////////////////////////////////////////////////////////////////////////////
 // Formatting
 
-impl<'i> fmt::Debug for Bytes<'i> {
+impl fmt::Debug for Bytes<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let display = InputDisplay::from_formatter(self, f);
         f.debug_struct("Bytes")
@@ -495,13 +785,13 @@ impl<'i> fmt::Debug for Bytes<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for Bytes<'i> {
+impl fmt::DisplayBase for Bytes<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         self.display().fmt(w)
     }
 }
 
-impl<'i> fmt::Display for Bytes<'i> {
+impl fmt::Display for Bytes<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         InputDisplay::from_formatter(self, f).fmt(f)
     }
@@ -511,4 +801,19 @@ impl<'i> fmt::Display for Bytes<'i> {
 // Zc
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for Bytes<'i> {}
+unsafe impl zc::NoInteriorMut for Bytes<'_> {}
+
+#[cfg(feature = "arbitrary")]
+impl<'i> arbitrary::Arbitrary<'i> for Bytes<'i> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'i>) -> arbitrary::Result<Self> {
+        <&'i [u8]>::arbitrary(u).map(crate::input)
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'i>) -> arbitrary::Result<Self> {
+        <&'i [u8]>::arbitrary_take_rest(u).map(crate::input)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&'i [u8]>::size_hint(depth)
+    }
+}
@@ -0,0 +1,439 @@
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::display::InputDisplay;
+use crate::error::{
+    ChunkContext, ExpectedContext, ExpectedLength, ExpectedValue, Length, OperationContext, Value,
+    WithContext,
+};
+use crate::fmt;
+use crate::reader::ChunksReader;
+
+use super::{Bound, Bytes, MaybeString};
+
+/// Input over a sequence of non-contiguous byte chunks.
+///
+/// `Chunks` are read with a [`ChunksReader`], which reads across chunk
+/// boundaries without copying them into one contiguous buffer. Input taken
+/// from a reader is returned as `Chunks` and only materialised into a
+/// contiguous copy when asked for with [`Chunks::to_contiguous()`].
+///
+/// Errors are relative to the chunk they occurred within, and carry a
+/// [`ChunkContext`] with where that chunk starts in the logical stream.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Chunks, Invalid};
+///
+/// let chunks: &[&[u8]] = &[b"\x00\x00", b"\x00\x05hel", b"lo"];
+///
+/// let result: Result<_, Invalid> = Chunks::new(chunks).read_all(|r| {
+///     let len = r.read_u32_be()?;
+///     r.take(len as usize)
+/// });
+/// let hello = result.unwrap();
+///
+/// assert_eq!(hello.offset(), 4);
+/// assert_eq!(hello.as_contiguous(), None);
+/// ```
+#[derive(Copy, Clone)]
+#[must_use = "input must be consumed"]
+pub struct Chunks<'i> {
+    inner: &'i [&'i [u8]],
+    /// The number of bytes of the first chunk before the input starts.
+    start: usize,
+    /// The total length of the input.
+    len: usize,
+    /// The index of the first chunk in the logical stream.
+    index: usize,
+    /// The offset of the start of the input in the logical stream.
+    offset: usize,
+    bound: Bound,
+}
+
+impl<'i> Chunks<'i> {
+    /// Creates `Chunks` over a sequence of byte chunks.
+    ///
+    /// Like [`dangerous::input()`], the start of the input is bound and the
+    /// end may change in further passes.
+    ///
+    /// [`dangerous::input()`]: crate::input()
+    pub fn new(chunks: &'i [&'i [u8]]) -> Self {
+        Self {
+            inner: chunks,
+            start: 0,
+            len: chunks.iter().map(|chunk| chunk.len()).sum(),
+            index: 0,
+            offset: 0,
+            bound: Bound::Start,
+        }
+    }
+
+    /// Returns the total length of the input across all chunks.
+    #[must_use]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the total length of the input is zero.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the offset of the start of the input in the logical stream.
+    #[must_use]
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the inner [`Bound`] of the input.
+    #[inline(always)]
+    pub fn bound(&self) -> Bound {
+        self.bound
+    }
+
+    /// Returns `self` as bound, see [`Input::into_bound()`].
+    ///
+    /// [`Input::into_bound()`]: crate::Input::into_bound()
+    #[inline(always)]
+    pub fn into_bound(mut self) -> Self {
+        self.bound = Bound::force_close();
+        self
+    }
+
+    /// Returns an iterator over the non-empty byte slices of the input.
+    pub fn slices(&self) -> impl Iterator<Item = &'i [u8]> {
+        self.parts().map(|part| part.bytes())
+    }
+
+    /// Returns the input as [`Bytes`] if it is contained within a single
+    /// chunk, and `None` if not.
+    ///
+    /// The returned `Bytes` carries the [`Bound`] of `self`.
+    #[must_use]
+    pub fn as_contiguous(&self) -> Option<Bytes<'i>> {
+        let mut parts = self.parts();
+        match (parts.next(), parts.next()) {
+            (None, _) => Some(Bytes::new(&[], self.bound)),
+            (Some(part), None) => Some(Bytes::new(part.bytes(), self.bound)),
+            (Some(_), Some(_)) => None,
+        }
+    }
+
+    /// Returns the input as a contiguous byte slice, only copying if it spans
+    /// more than one chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Chunks;
+    ///
+    /// let chunks: &[&[u8]] = &[b"hel", b"lo"];
+    ///
+    /// assert_eq!(Chunks::new(chunks).to_contiguous(), &b"hello"[..]);
+    /// ```
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_contiguous(&self) -> Cow<'i, [u8]> {
+        if let Some(bytes) = self.as_contiguous() {
+            return Cow::Borrowed(bytes.as_dangerous());
+        }
+        let mut buf = Vec::with_capacity(self.len);
+        for bytes in self.slices() {
+            buf.extend_from_slice(bytes);
+        }
+        Cow::Owned(buf)
+    }
+
+    /// Create a reader with the expectation all of the input is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the provided function does, or there is
+    /// trailing input.
+    pub fn read_all<F, T, E>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut ChunksReader<'i, E>) -> Result<T, E>,
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        let mut r = ChunksReader::new(self);
+        let ok = r.context(OperationContext("read all"), f)?;
+        match r.take_remaining().parts().next() {
+            None => Ok(ok),
+            Some(part) => Err(part.with_context(E::from(ExpectedLength {
+                len: Length::Exactly(0),
+                span: part.bytes(),
+                input: part.input(),
+                context: ExpectedContext {
+                    operation: "read all",
+                    expected: "no trailing input",
+                },
+            }))),
+        }
+    }
+
+    /// Create a reader to read a part of the input and return the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided function does.
+    pub fn read_partial<F, T, E>(self, f: F) -> Result<(T, Chunks<'i>), E>
+    where
+        F: FnOnce(&mut ChunksReader<'i, E>) -> Result<T, E>,
+        E: WithContext<'i>,
+    {
+        let mut r = ChunksReader::new(self);
+        let ok = r.context(OperationContext("read partial"), f)?;
+        Ok((ok, r.take_remaining()))
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    /// Returns an iterator over the parts of the chunks within the input.
+    pub(crate) fn parts(&self) -> Parts<'i> {
+        Parts {
+            chunks: self.inner,
+            start: self.start,
+            remaining: self.len,
+            index: self.index,
+            offset: self.offset - self.start,
+            bound: self.bound,
+        }
+    }
+
+    /// Returns the last part of the input, or an empty part at the start of
+    /// the input if it is empty.
+    pub(crate) fn end_part(&self) -> Part<'i> {
+        self.parts().last().unwrap_or_else(|| {
+            let chunk = self.inner.first().copied().unwrap_or(&[]);
+            Part {
+                chunk,
+                range: self.start..self.start,
+                index: self.index,
+                offset: self.offset - self.start,
+                bound: self.bound,
+            }
+        })
+    }
+
+    /// Splits the input at `mid`, which must not be greater than the length.
+    pub(crate) fn split_at(self, mid: usize) -> (Self, Self) {
+        debug_assert!(mid <= self.len);
+        let head = Self {
+            len: mid,
+            bound: self.bound.close_end(),
+            ..self
+        };
+        let mut tail = self;
+        let mut skip = mid;
+        while let Some((chunk, rest)) = tail.inner.split_first() {
+            let available = chunk.len() - tail.start;
+            if rest.is_empty() || skip < available {
+                break;
+            }
+            skip -= available;
+            tail.inner = rest;
+            tail.start = 0;
+            tail.index += 1;
+        }
+        tail.start += skip;
+        tail.len -= mid;
+        tail.offset += mid;
+        (head, tail)
+    }
+
+    /// Copies the input into `buf`, which must be the same length.
+    pub(crate) fn copy_to(&self, buf: &mut [u8]) {
+        let mut pos = 0;
+        for bytes in self.slices() {
+            buf[pos..pos + bytes.len()].copy_from_slice(bytes);
+            pos += bytes.len();
+        }
+    }
+
+    /// Returns an error for `len` bytes being required when there is not
+    /// enough input.
+    pub(crate) fn expected_length<E>(&self, len: usize, operation: &'static str) -> E
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        let part = self.end_part();
+        let span = part.bytes();
+        part.with_context(E::from(ExpectedLength {
+            len: Length::AtLeast(span.len() + len.saturating_sub(self.len)),
+            span,
+            input: part.input(),
+            context: ExpectedContext {
+                operation,
+                expected: "enough input",
+            },
+        }))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Parts
+
+/// A part of a chunk within [`Chunks`].
+#[derive(Clone)]
+pub(crate) struct Part<'i> {
+    chunk: &'i [u8],
+    range: Range<usize>,
+    index: usize,
+    /// The offset of the start of the chunk in the logical stream.
+    offset: usize,
+    bound: Bound,
+}
+
+impl<'i> Part<'i> {
+    /// Returns the bytes of the chunk within the input.
+    pub(crate) fn bytes(&self) -> &'i [u8] {
+        &self.chunk[self.range.clone()]
+    }
+
+    /// Returns the whole chunk as input.
+    pub(crate) fn chunk_input(&self) -> Bytes<'i> {
+        Bytes::new(self.chunk, self.bound)
+    }
+
+    /// Returns the whole chunk as input for an error.
+    pub(crate) fn input(&self) -> MaybeString<'i> {
+        MaybeString::Bytes(self.chunk_input())
+    }
+
+    /// Returns an error for `actual` not matching the `expected` value.
+    pub(crate) fn expected_value<E>(
+        &self,
+        actual: &'i [u8],
+        expected: Value<'i>,
+        context: ExpectedContext,
+    ) -> E
+    where
+        E: From<ExpectedValue<'i>> + WithContext<'i>,
+    {
+        self.with_context(E::from(ExpectedValue {
+            actual,
+            expected,
+            input: self.input(),
+            context,
+        }))
+    }
+
+    /// Adds the [`ChunkContext`] of the part to an error.
+    pub(crate) fn with_context<E>(&self, error: E) -> E
+    where
+        E: WithContext<'i>,
+    {
+        let context = ChunkContext {
+            index: self.index,
+            offset: self.offset,
+        };
+        error.with_context(self.chunk_input(), context)
+    }
+}
+
+/// An iterator over the non-empty [`Part`]s within [`Chunks`].
+pub(crate) struct Parts<'i> {
+    chunks: &'i [&'i [u8]],
+    start: usize,
+    remaining: usize,
+    index: usize,
+    offset: usize,
+    bound: Bound,
+}
+
+impl<'i> Iterator for Parts<'i> {
+    type Item = Part<'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let (chunk, rest) = self.chunks.split_first()?;
+            let end = chunk.len().min(self.start + self.remaining);
+            let range = self.start..end;
+            let index = self.index;
+            let offset = self.offset;
+            self.remaining -= range.len();
+            self.chunks = rest;
+            self.start = 0;
+            self.index += 1;
+            self.offset += chunk.len();
+            if !range.is_empty() {
+                // Only the part at the end of the input may change in
+                // further passes.
+                let bound = if self.remaining == 0 {
+                    self.bound
+                } else {
+                    Bound::force_close()
+                };
+                return Some(Part {
+                    chunk,
+                    range,
+                    index,
+                    offset,
+                    bound,
+                });
+            }
+        }
+        None
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Equality
+
+impl PartialEq<[u8]> for Chunks<'_> {
+    fn eq(&self, other: &[u8]) -> bool {
+        if self.len != other.len() {
+            return false;
+        }
+        let mut other = other;
+        self.slices().all(|bytes| {
+            let (head, tail) = other.split_at(bytes.len());
+            other = tail;
+            head == bytes
+        })
+    }
+}
+
+impl PartialEq<&[u8]> for Chunks<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        *self == **other
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Formatting
+
+impl fmt::Debug for Chunks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chunks")
+            .field("bound", &self.bound)
+            .field("offset", &self.offset)
+            .field("slices", &DebugSlices(self))
+            .finish()
+    }
+}
+
+struct DebugSlices<'a, 'i>(&'a Chunks<'i>);
+
+impl fmt::Debug for DebugSlices<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.0
+                    .slices()
+                    .map(|bytes| InputDisplay::new(&Bytes::new(bytes, Bound::Both))),
+            )
+            .finish()
+    }
+}
@@ -55,7 +55,7 @@ impl<'i> MaybeString<'i> {
 ///////////////////////////////////////////////////////////////////////////////
 // Formatting
 
-impl<'i> fmt::Debug for MaybeString<'i> {
+impl fmt::Debug for MaybeString<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Bytes(v) => v.fmt(f),
@@ -64,13 +64,13 @@ impl<'i> fmt::Debug for MaybeString<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for MaybeString<'i> {
+impl fmt::DisplayBase for MaybeString<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         self.display().fmt(w)
     }
 }
 
-impl<'i> fmt::Display for MaybeString<'i> {
+impl fmt::Display for MaybeString<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display().fmt(f)
     }
@@ -1,20 +1,35 @@
 mod bytes;
+mod chunks;
 mod maybe;
+#[cfg(feature = "nom")]
+mod nom;
 mod prefix;
+mod printable;
+#[cfg(feature = "alloc")]
+mod shared;
+mod split;
 mod string;
 mod traits;
+mod utf16;
 
-pub(crate) mod pattern;
+pub mod pattern;
 
 use crate::fmt;
 
 pub use self::bytes::Bytes;
+pub use self::chunks::Chunks;
 pub use self::maybe::MaybeString;
 pub use self::pattern::Pattern;
+pub use self::printable::AllowedControls;
+#[cfg(feature = "alloc")]
+pub use self::shared::SharedBytes;
+pub use self::split::{Lines, Split, SplitN};
 pub use self::string::String;
 pub use self::traits::Input;
+pub use self::utf16::Utf16;
 
 pub(crate) use self::prefix::Prefix;
+pub(crate) use self::printable::is_bidi_control;
 pub(crate) use self::traits::{BytesLength, IntoInput, Private, PrivateExt};
 
 /// Creates a new `Input` from a byte or string slice.
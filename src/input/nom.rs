@@ -0,0 +1,315 @@
+use core::iter::{Copied, Enumerate};
+use core::ops::{Range, RangeFrom, RangeFull, RangeTo};
+use core::slice::Iter;
+
+use nom::error::{Error as NomError, ErrorKind, ParseError};
+use nom::{
+    AsBytes, Compare, CompareResult, Err as NomErr, FindSubstring, IResult, InputIter, InputLength,
+    InputTake, InputTakeAtPosition, Needed, Offset, Parser, Slice,
+};
+
+use crate::error::{ExpectedContext, ExpectedValid};
+use crate::util::slice;
+
+use super::{Bytes, Input, Private};
+
+/// Input a `nom` parser can be run on by a [`Reader`](crate::Reader).
+pub(crate) trait NomInput<'i>: Sized {
+    fn from_bytes(bytes: Bytes<'i>) -> Self;
+
+    fn as_nom_bytes(&self) -> &'i [u8];
+}
+
+impl<'i> NomInput<'i> for &'i [u8] {
+    #[inline(always)]
+    fn from_bytes(bytes: Bytes<'i>) -> Self {
+        bytes.as_dangerous()
+    }
+
+    #[inline(always)]
+    fn as_nom_bytes(&self) -> &'i [u8] {
+        self
+    }
+}
+
+impl<'i> NomInput<'i> for Bytes<'i> {
+    #[inline(always)]
+    fn from_bytes(bytes: Bytes<'i>) -> Self {
+        bytes
+    }
+
+    #[inline(always)]
+    fn as_nom_bytes(&self) -> &'i [u8] {
+        self.as_dangerous()
+    }
+}
+
+impl<'i> Bytes<'i> {
+    /// Runs a `nom` parser on the input, splitting off what it consumed.
+    pub(crate) fn split_nom<I, O, P, E>(
+        self,
+        mut parser: P,
+        expected: &'static str,
+        operation: &'static str,
+    ) -> Result<(O, Bytes<'i>), E>
+    where
+        I: NomInput<'i>,
+        P: Parser<I, O, NomError<I>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        let bytes = self.as_dangerous();
+        let (span, retry) = match parser.parse(I::from_bytes(self.clone())) {
+            Ok((rest, ok)) => match suffix_offset(bytes, rest.as_nom_bytes()) {
+                Some(consumed) => {
+                    // SAFETY: the remaining input was checked to be a suffix
+                    // of the input at `consumed`.
+                    let (_, tail) = unsafe { self.split_at_byte_unchecked(consumed) };
+                    return Ok((ok, tail));
+                }
+                None => (bytes, None),
+            },
+            Err(NomErr::Incomplete(needed)) => {
+                let needed = match needed {
+                    Needed::Size(n) => n.get(),
+                    Needed::Unknown => 1,
+                };
+                (bytes, if self.is_bound() { None } else { Some(needed) })
+            }
+            Err(NomErr::Error(err) | NomErr::Failure(err)) => {
+                let err = err.input.as_nom_bytes();
+                match suffix_offset(bytes, err) {
+                    Some(_) => (err, None),
+                    None => (bytes, None),
+                }
+            }
+        };
+        #[cfg(not(feature = "retry"))]
+        let _ = retry;
+        Err(E::from(ExpectedValid {
+            span,
+            #[cfg(feature = "retry")]
+            retry_requirement: retry.and_then(crate::error::RetryRequirement::new),
+            input: self.into_maybe_string(),
+            context: ExpectedContext {
+                operation,
+                expected,
+            },
+        }))
+    }
+}
+
+/// Returns the offset of `suffix` if it ends the parent `bytes`.
+fn suffix_offset(bytes: &[u8], suffix: &[u8]) -> Option<usize> {
+    if slice::is_sub_slice(bytes, suffix) && bytes.as_ptr_range().end == suffix.as_ptr_range().end {
+        Some(bytes.len() - suffix.len())
+    } else {
+        None
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// nom input traits
+
+impl Bytes<'_> {
+    /// Splits at `mid`, returning `(tail, head)` as `nom` expects.
+    #[inline(always)]
+    fn nom_split(&self, mid: usize) -> (Self, Self) {
+        let (head, tail) = self.as_dangerous().split_at(mid);
+        (
+            Bytes::new(tail, self.bound()),
+            Bytes::new(head, self.bound().close_end()),
+        )
+    }
+
+    /// Splits at the position of the first token matching `predicate`.
+    ///
+    /// If there is no match and the input is not bound, more input is needed.
+    /// Otherwise the whole input is taken.
+    fn nom_split_at_position<P, E>(
+        &self,
+        predicate: P,
+        min: Option<ErrorKind>,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(u8) -> bool,
+        E: ParseError<Self>,
+    {
+        let mid = match self.as_dangerous().iter().position(|b| predicate(*b)) {
+            Some(mid) => mid,
+            None if self.is_bound() => self.len(),
+            None => return Err(NomErr::Incomplete(Needed::new(1))),
+        };
+        match min {
+            Some(kind) if mid == 0 => Err(NomErr::Error(E::from_error_kind(self.clone(), kind))),
+            _ => Ok(self.nom_split(mid)),
+        }
+    }
+}
+
+impl InputLength for Bytes<'_> {
+    #[inline(always)]
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl InputTake for Bytes<'_> {
+    #[inline(always)]
+    fn take(&self, count: usize) -> Self {
+        self.nom_split(count).1
+    }
+
+    #[inline(always)]
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        self.nom_split(count)
+    }
+}
+
+impl<'i> InputIter for Bytes<'i> {
+    type Item = u8;
+    type Iter = Enumerate<Self::IterElem>;
+    type IterElem = Copied<Iter<'i, u8>>;
+
+    #[inline(always)]
+    fn iter_indices(&self) -> Self::Iter {
+        self.iter_elements().enumerate()
+    }
+
+    #[inline(always)]
+    fn iter_elements(&self) -> Self::IterElem {
+        self.as_dangerous().iter().copied()
+    }
+
+    #[inline(always)]
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(Self::Item) -> bool,
+    {
+        self.as_dangerous().iter().position(|b| predicate(*b))
+    }
+
+    #[inline(always)]
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.as_dangerous().slice_index(count)
+    }
+}
+
+impl InputTakeAtPosition for Bytes<'_> {
+    type Item = u8;
+
+    fn split_at_position<P, E>(&self, predicate: P) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+        E: ParseError<Self>,
+    {
+        self.nom_split_at_position(predicate, None)
+    }
+
+    fn split_at_position1<P, E>(&self, predicate: P, e: ErrorKind) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+        E: ParseError<Self>,
+    {
+        self.nom_split_at_position(predicate, Some(e))
+    }
+
+    fn split_at_position_complete<P, E>(&self, predicate: P) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+        E: ParseError<Self>,
+    {
+        Ok(self.nom_split(self.position(predicate).unwrap_or_else(|| self.len())))
+    }
+
+    fn split_at_position1_complete<P, E>(
+        &self,
+        predicate: P,
+        e: ErrorKind,
+    ) -> IResult<Self, Self, E>
+    where
+        P: Fn(Self::Item) -> bool,
+        E: ParseError<Self>,
+    {
+        match self.position(predicate).unwrap_or_else(|| self.len()) {
+            0 => Err(NomErr::Error(E::from_error_kind(self.clone(), e))),
+            mid => Ok(self.nom_split(mid)),
+        }
+    }
+}
+
+impl Slice<Range<usize>> for Bytes<'_> {
+    #[inline(always)]
+    fn slice(&self, range: Range<usize>) -> Self {
+        if range.end == self.len() {
+            Bytes::new(&self.as_dangerous()[range], self.bound())
+        } else {
+            Bytes::new(&self.as_dangerous()[range], self.bound().close_end())
+        }
+    }
+}
+
+impl Slice<RangeFrom<usize>> for Bytes<'_> {
+    #[inline(always)]
+    fn slice(&self, range: RangeFrom<usize>) -> Self {
+        Bytes::new(&self.as_dangerous()[range], self.bound())
+    }
+}
+
+impl Slice<RangeTo<usize>> for Bytes<'_> {
+    #[inline(always)]
+    fn slice(&self, range: RangeTo<usize>) -> Self {
+        self.slice(0..range.end)
+    }
+}
+
+impl Slice<RangeFull> for Bytes<'_> {
+    #[inline(always)]
+    fn slice(&self, _: RangeFull) -> Self {
+        self.clone()
+    }
+}
+
+impl<'a> Compare<&'a [u8]> for Bytes<'_> {
+    #[inline(always)]
+    fn compare(&self, t: &'a [u8]) -> CompareResult {
+        self.as_dangerous().compare(t)
+    }
+
+    #[inline(always)]
+    fn compare_no_case(&self, t: &'a [u8]) -> CompareResult {
+        self.as_dangerous().compare_no_case(t)
+    }
+}
+
+impl<'a> Compare<&'a str> for Bytes<'_> {
+    #[inline(always)]
+    fn compare(&self, t: &'a str) -> CompareResult {
+        self.as_dangerous().compare(t)
+    }
+
+    #[inline(always)]
+    fn compare_no_case(&self, t: &'a str) -> CompareResult {
+        self.as_dangerous().compare_no_case(t)
+    }
+}
+
+impl<'a> FindSubstring<&'a [u8]> for Bytes<'_> {
+    #[inline(always)]
+    fn find_substring(&self, substr: &'a [u8]) -> Option<usize> {
+        self.as_dangerous().find_substring(substr)
+    }
+}
+
+impl Offset for Bytes<'_> {
+    #[inline(always)]
+    fn offset(&self, second: &Self) -> usize {
+        self.as_dangerous().offset(second.as_dangerous())
+    }
+}
+
+impl AsBytes for Bytes<'_> {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_dangerous()
+    }
+}
@@ -0,0 +1,518 @@
+use crate::error::Value;
+use crate::input::{Bytes, Input, Private, String};
+
+use super::Pattern;
+
+///////////////////////////////////////////////////////////////////////////////
+// AnyOf
+
+/// Returns a [`Pattern`] that matches any single byte within `set`.
+///
+/// Sets of up to three bytes are searched for with `memchr` when enabled.
+///
+/// When used with [`String`] input, only the ASCII bytes within `set` can
+/// match.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"key=value;").read_all(|r| {
+///     let key = r.take_until_opt(pattern::any_of(b"=;"));
+///     r.skip(1)?;
+///     let value = r.take_until_opt(pattern::any_of(b"=;"));
+///     r.skip(1)?;
+///     Ok((key, value))
+/// });
+///
+/// assert_eq!(result.unwrap(), (dangerous::input(b"key"), dangerous::input(b"value")));
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn any_of(set: &[u8]) -> AnyOf<'_> {
+    AnyOf(set)
+}
+
+/// A [`Pattern`] created with [`any_of()`].
+#[derive(Debug, Copy, Clone)]
+pub struct AnyOf<'p>(&'p [u8]);
+
+impl AnyOf<'_> {
+    fn table(self) -> [bool; 256] {
+        let mut table = [false; 256];
+        for b in self.0.iter().copied() {
+            table[usize::from(b)] = true;
+        }
+        table
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for AnyOf<'_> {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        let bytes = input.as_dangerous();
+        let index = match *self.0 {
+            [] => None,
+            #[cfg(feature = "memchr")]
+            [a] => memchr::memchr(a, bytes),
+            #[cfg(feature = "memchr")]
+            [a, b] => memchr::memchr2(a, b, bytes),
+            #[cfg(feature = "memchr")]
+            [a, b, c] => memchr::memchr3(a, b, c, bytes),
+            _ => {
+                let table = self.table();
+                bytes.iter().position(|b| table[usize::from(*b)])
+            }
+        };
+        index.map(|index| (index, 1))
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        let table = self.table();
+        input
+            .as_dangerous()
+            .iter()
+            .position(|b| !table[usize::from(*b)])
+    }
+}
+
+unsafe impl<'i> Pattern<String<'i>> for AnyOf<'_> {
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        if self.0.is_ascii() {
+            // ASCII bytes are always on a char boundary.
+            <Self as Pattern<Bytes<'i>>>::find_match(self, &input.clone().into_bytes())
+        } else {
+            let table = self.table();
+            input.as_dangerous().char_indices().find_map(|(i, c)| {
+                if c.is_ascii() && table[c as usize] {
+                    Some((i, 1))
+                } else {
+                    None
+                }
+            })
+        }
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        if self.0.is_ascii() {
+            // The first byte rejected is either ASCII or the start of a char.
+            <Self as Pattern<Bytes<'i>>>::find_reject(self, &input.clone().into_bytes())
+        } else {
+            let table = self.table();
+            input.as_dangerous().char_indices().find_map(|(i, c)| {
+                if c.is_ascii() && table[c as usize] {
+                    None
+                } else {
+                    Some(i)
+                }
+            })
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Range
+
+/// Returns a [`Pattern`] that matches any single byte within `range`.
+///
+/// When used with [`String`] input, only ASCII bytes within `range` can
+/// match.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"123abc").read_all(|r| {
+///     let digits = r.take_while(pattern::range(b'0'..=b'9'));
+///     r.skip(3)?;
+///     Ok(digits)
+/// });
+///
+/// assert_eq!(result.unwrap(), b"123"[..]);
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn range(range: core::ops::RangeInclusive<u8>) -> Range {
+    Range {
+        start: *range.start(),
+        end: *range.end(),
+    }
+}
+
+/// A [`Pattern`] created with [`range()`].
+#[derive(Debug, Copy, Clone)]
+pub struct Range {
+    start: u8,
+    end: u8,
+}
+
+impl Range {
+    #[inline(always)]
+    fn contains(self, b: u8) -> bool {
+        self.start <= b && b <= self.end
+    }
+
+    #[inline(always)]
+    fn contains_char(self, c: char) -> bool {
+        c.is_ascii() && self.contains(c as u8)
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for Range {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        input
+            .as_dangerous()
+            .iter()
+            .position(|b| self.contains(*b))
+            .map(|index| (index, 1))
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        input.as_dangerous().iter().position(|b| !self.contains(*b))
+    }
+}
+
+unsafe impl<'i> Pattern<String<'i>> for Range {
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        input.as_dangerous().char_indices().find_map(|(i, c)| {
+            if self.contains_char(c) {
+                Some((i, 1))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        input.as_dangerous().char_indices().find_map(|(i, c)| {
+            if self.contains_char(c) {
+                None
+            } else {
+                Some(i)
+            }
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Seq
+
+/// Returns a [`Pattern`] that matches the exact sequence of bytes `seq`.
+///
+/// When searched for with `memchr` enabled, candidates are found with the
+/// first byte of the sequence.
+///
+/// When used with [`String`] input, `seq` can only match if it is valid
+/// UTF-8.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"line\r\nnext").read_all(|r| {
+///     let line = r.take_until_consume(pattern::seq(b"\r\n"))?;
+///     Ok((line, r.take_remaining()))
+/// });
+///
+/// assert_eq!(result.unwrap(), (dangerous::input(b"line"), dangerous::input(b"next")));
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn seq(seq: &[u8]) -> Seq<'_> {
+    Seq(seq)
+}
+
+/// A [`Pattern`] created with [`seq()`].
+#[derive(Debug, Copy, Clone)]
+pub struct Seq<'p>(&'p [u8]);
+
+unsafe impl<'i> Pattern<Bytes<'i>> for Seq<'_> {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        let bytes = input.as_dangerous();
+        let first = match self.0.first() {
+            Some(first) => *first,
+            None => return Some((0, 0)),
+        };
+        let mut offset = 0;
+        loop {
+            // SAFETY: offset is always within the input.
+            let (_, tail) = unsafe { input.clone().split_at_byte_unchecked(offset) };
+            let index = offset + Pattern::find_match(first, &tail)?.0;
+            if bytes[index..].starts_with(self.0) {
+                return Some((index, self.0.len()));
+            }
+            offset = index + 1;
+        }
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        let bytes = input.as_dangerous();
+        if self.0.is_empty() {
+            return if bytes.is_empty() { None } else { Some(0) };
+        }
+        let mut index = 0;
+        while bytes[index..].starts_with(self.0) {
+            index += self.0.len();
+        }
+        if index == bytes.len() {
+            None
+        } else {
+            Some(index)
+        }
+    }
+}
+
+unsafe impl<'i> Pattern<String<'i>> for Seq<'_> {
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        // A valid UTF-8 sequence can only be found on char boundaries.
+        if core::str::from_utf8(self.0).is_ok() {
+            <Self as Pattern<Bytes<'i>>>::find_match(self, &input.clone().into_bytes())
+        } else {
+            None
+        }
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        if core::str::from_utf8(self.0).is_ok() {
+            <Self as Pattern<Bytes<'i>>>::find_reject(self, &input.clone().into_bytes())
+        } else if input.is_empty() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl<'p> From<Seq<'p>> for Value<'p> {
+    #[inline(always)]
+    fn from(pattern: Seq<'p>) -> Self {
+        Value::from(pattern.0)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// AsciiCi
+
+/// Returns a [`Pattern`] that matches `literal`, ignoring ASCII case.
+///
+/// Errors will display `literal` as given.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"body--Boundary").read_all(|r| {
+///     let body = r.take_until_consume(pattern::ascii_ci("--boundary"))?;
+///     Ok(body)
+/// });
+///
+/// assert_eq!(result.unwrap(), b"body"[..]);
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn ascii_ci(literal: &str) -> AsciiCi<'_> {
+    AsciiCi(literal)
+}
+
+/// A [`Pattern`] created with [`ascii_ci()`].
+#[derive(Debug, Copy, Clone)]
+pub struct AsciiCi<'p>(&'p str);
+
+impl AsciiCi<'_> {
+    #[inline(always)]
+    fn is_prefix_of(self, bytes: &[u8]) -> bool {
+        match bytes.get(..self.0.len()) {
+            Some(head) => head.eq_ignore_ascii_case(self.0.as_bytes()),
+            None => false,
+        }
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for AsciiCi<'_> {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        let bytes = input.as_dangerous();
+        let first = match self.0.as_bytes().first() {
+            Some(first) => *first,
+            None => return Some((0, 0)),
+        };
+        let first = [first.to_ascii_lowercase(), first.to_ascii_uppercase()];
+        let candidates = any_of(&first);
+        let mut offset = 0;
+        loop {
+            // SAFETY: offset is always within the input.
+            let (_, tail) = unsafe { input.clone().split_at_byte_unchecked(offset) };
+            let index = offset + Pattern::<Bytes<'i>>::find_match(candidates, &tail)?.0;
+            if self.is_prefix_of(&bytes[index..]) {
+                return Some((index, self.0.len()));
+            }
+            offset = index + 1;
+        }
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        let bytes = input.as_dangerous();
+        if self.0.is_empty() {
+            return if bytes.is_empty() { None } else { Some(0) };
+        }
+        let mut index = 0;
+        while self.is_prefix_of(&bytes[index..]) {
+            index += self.0.len();
+        }
+        if index == bytes.len() {
+            None
+        } else {
+            Some(index)
+        }
+    }
+}
+
+unsafe impl<'i> Pattern<String<'i>> for AsciiCi<'_> {
+    // The literal is valid UTF-8 and only differs from a match by ASCII case,
+    // so a match always starts and ends on a char boundary.
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        <Self as Pattern<Bytes<'i>>>::find_match(self, &input.clone().into_bytes())
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        <Self as Pattern<Bytes<'i>>>::find_reject(self, &input.clone().into_bytes())
+    }
+}
+
+impl<'p> From<AsciiCi<'p>> for Value<'p> {
+    #[inline(always)]
+    fn from(pattern: AsciiCi<'p>) -> Self {
+        Value::from(pattern.0)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Not
+
+/// Returns a [`Pattern`] that matches any single token `pattern` does not.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"abc123").read_all(|r| {
+///     let letters = r.take_until_opt(pattern::not(pattern::range(b'a'..=b'z')));
+///     r.skip(3)?;
+///     Ok(letters)
+/// });
+///
+/// assert_eq!(result.unwrap(), b"abc"[..]);
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn not<P>(pattern: P) -> Not<P> {
+    Not(pattern)
+}
+
+/// A [`Pattern`] created with [`not()`].
+#[derive(Debug, Copy, Clone)]
+pub struct Not<P>(P);
+
+unsafe impl<'i, P> Pattern<Bytes<'i>> for Not<P>
+where
+    P: Pattern<Bytes<'i>>,
+{
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        self.0.find_reject(input).map(|index| (index, 1))
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        self.0.find_match(input).map(|(index, _)| index)
+    }
+}
+
+unsafe impl<'i, P> Pattern<String<'i>> for Not<P>
+where
+    P: Pattern<String<'i>>,
+{
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        let string = input.as_dangerous();
+        self.0.find_reject(input).and_then(|index| {
+            // Pattern guarantees the index is on a char boundary.
+            string
+                .get(index..)
+                .and_then(|tail| tail.chars().next())
+                .map(|c| (index, c.len_utf8()))
+        })
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        self.0.find_match(input).map(|(index, _)| index)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Or
+
+/// Returns a [`Pattern`] that matches either `left` or `right`.
+///
+/// When searching, the earliest match is returned, preferring `left` if both
+/// match at the same index.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"ab-cd_ef").read_all(|r| {
+///     let word = r.take_while(pattern::or(pattern::range(b'a'..=b'z'), b'-'));
+///     r.skip(3)?;
+///     Ok(word)
+/// });
+///
+/// assert_eq!(result.unwrap(), b"ab-cd"[..]);
+/// ```
+#[must_use]
+#[inline(always)]
+pub fn or<L, R>(left: L, right: R) -> Or<L, R> {
+    Or(left, right)
+}
+
+/// A [`Pattern`] created with [`or()`].
+#[derive(Debug, Copy, Clone)]
+pub struct Or<L, R>(L, R);
+
+macro_rules! impl_or_pattern {
+    ($input:ident) => {
+        unsafe impl<'i, L, R> Pattern<$input<'i>> for Or<L, R>
+        where
+            L: Pattern<$input<'i>> + Copy,
+            R: Pattern<$input<'i>> + Copy,
+        {
+            fn find_match(self, input: &$input<'i>) -> Option<(usize, usize)> {
+                match (self.0.find_match(input), self.1.find_match(input)) {
+                    (Some(left), Some(right)) if right.0 < left.0 => Some(right),
+                    (Some(left), _) => Some(left),
+                    (None, right) => right,
+                }
+            }
+
+            fn find_reject(self, input: &$input<'i>) -> Option<usize> {
+                let mut offset = 0;
+                loop {
+                    // SAFETY: offset is always an index returned by a pattern.
+                    let (_, tail) = unsafe { input.clone().split_at_byte_unchecked(offset) };
+                    let accepted = match (self.0.find_reject(&tail), self.1.find_reject(&tail)) {
+                        (None, _) | (_, None) => return None,
+                        (Some(left), Some(right)) => left.max(right),
+                    };
+                    if accepted == 0 {
+                        return Some(offset);
+                    }
+                    offset += accepted;
+                }
+            }
+        }
+    };
+}
+
+impl_or_pattern!(Bytes);
+impl_or_pattern!(String);
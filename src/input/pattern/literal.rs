@@ -0,0 +1,131 @@
+use aho_corasick::{AhoCorasick, Anchored, BuildError, MatchKind, StartKind};
+
+use crate::input::{Bytes, MaybeString, String};
+
+use super::Pattern;
+
+/// Returns a [`Pattern`] that matches the first of any of the `literals`,
+/// searched for with the Aho-Corasick algorithm.
+///
+/// If more than one literal matches at the same index, the literal that was
+/// provided first is matched. Use [`Reader::take_until_literal()`] to find out
+/// which of the literals matched.
+///
+/// When used with [`String`] input, matches that do not start and end on a
+/// char boundary are skipped.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, pattern};
+///
+/// let newline = pattern::any_literal(&["\r\n", "\n"]).unwrap();
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"a\nb\r\n").read_all(|r| {
+///     let a = r.take_until_consume_opt(&newline).0;
+///     let b = r.take_until_consume_opt(&newline).0;
+///     Ok((a, b))
+/// });
+///
+/// assert_eq!(result.unwrap(), (dangerous::input(b"a"), dangerous::input(b"b")));
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`BuildError`] if the automaton for the literals exceeds the
+/// internal limits of the `aho-corasick` crate.
+///
+/// [`Reader::take_until_literal()`]: crate::Reader::take_until_literal()
+#[cfg_attr(docsrs, doc(cfg(feature = "aho-corasick")))]
+pub fn any_literal<I, P>(literals: I) -> Result<AnyLiteral, BuildError>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<[u8]>,
+{
+    AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostFirst)
+        .start_kind(StartKind::Both)
+        .build(literals)
+        .map(AnyLiteral)
+}
+
+/// A [`Pattern`] created with [`any_literal()`].
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "aho-corasick")))]
+pub struct AnyLiteral(AhoCorasick);
+
+impl AnyLiteral {
+    /// Returns the number of literals.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.patterns_len()
+    }
+
+    /// Returns `true` if there are no literals.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte index, byte length and literal index of the first
+    /// match within the input.
+    pub(crate) fn find_literal(&self, input: &MaybeString<'_>) -> Option<(usize, usize, usize)> {
+        let bytes = input.clone().into_bytes().as_dangerous();
+        let mut search = aho_corasick::Input::new(bytes);
+        loop {
+            let found = self.0.find(search.clone())?;
+            if is_char_boundary(input, found.start()) && is_char_boundary(input, found.end()) {
+                return Some((found.start(), found.len(), found.pattern().as_usize()));
+            }
+            search.set_start(found.start() + 1);
+        }
+    }
+
+    /// Returns the byte index of the first input not covered by a run of
+    /// literals.
+    fn find_literal_reject(&self, input: &MaybeString<'_>) -> Option<usize> {
+        let bytes = input.clone().into_bytes().as_dangerous();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let search = aho_corasick::Input::new(bytes)
+                .range(offset..)
+                .anchored(Anchored::Yes);
+            match self.0.find(search) {
+                Some(found) if !found.is_empty() && is_char_boundary(input, found.end()) => {
+                    offset = found.end();
+                }
+                _ => return Some(offset),
+            }
+        }
+        None
+    }
+}
+
+fn is_char_boundary(input: &MaybeString<'_>, index: usize) -> bool {
+    match input {
+        MaybeString::Bytes(_) => true,
+        MaybeString::String(s) => s.as_dangerous().is_char_boundary(index),
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for &AnyLiteral {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        self.find_literal(&MaybeString::Bytes(input.clone()))
+            .map(|(index, len, _)| (index, len))
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        self.find_literal_reject(&MaybeString::Bytes(input.clone()))
+    }
+}
+
+unsafe impl<'i> Pattern<String<'i>> for &AnyLiteral {
+    fn find_match(self, input: &String<'i>) -> Option<(usize, usize)> {
+        self.find_literal(&MaybeString::String(input.clone()))
+            .map(|(index, len, _)| (index, len))
+    }
+
+    fn find_reject(self, input: &String<'i>) -> Option<usize> {
+        self.find_literal_reject(&MaybeString::String(input.clone()))
+    }
+}
@@ -1,5 +1,36 @@
+//! [`Pattern`] support and combinators.
+//!
+//! Besides the combinators within this module, [`Pattern`] is implemented for:
+//!
+//! - `u8` for [`Bytes`] input.
+//! - `char` for [`String`] input.
+//! - Closures taking a token, such as `FnMut(u8) -> bool` for [`Bytes`] input.
+//! - `&regex::Regex` and `&regex::bytes::Regex` with the `regex` feature.
+//! - `&AnyLiteral` created with `any_literal()` with the `aho-corasick`
+//!   feature.
+//!
+//! Only patterns that can be converted into a [`Value`] can be used with
+//! methods that fail if the pattern could not be found, such as
+//! [`Reader::take_until()`]. Patterns created with [`not()`], [`or()`] and
+//! [`range()`] can be used with the optional variants such as
+//! [`Reader::take_until_opt()`].
+//!
+//! [`Value`]: crate::error::Value
+//! [`Reader::take_until()`]: crate::Reader::take_until()
+//! [`Reader::take_until_opt()`]: crate::Reader::take_until_opt()
+
+mod combinator;
+#[cfg(feature = "aho-corasick")]
+mod literal;
+
 use crate::input::{Bytes, String};
 
+pub use self::combinator::{
+    any_of, ascii_ci, not, or, range, seq, AnyOf, AsciiCi, Not, Or, Range, Seq,
+};
+#[cfg(feature = "aho-corasick")]
+pub use self::literal::{any_literal, AnyLiteral};
+
 /// A structure that can be found within an [`Input`](crate::Input).
 ///
 /// # Safety
@@ -2,11 +2,17 @@ use super::{Bytes, BytesLength, String};
 
 use crate::util::utf8::CharBytes;
 
+/// # Safety
+///
+/// `is_prefix_of` must only return `true` if the input is at least
+/// `byte_len()` bytes long and starts with `self`.
 pub unsafe trait Prefix<I>: BytesLength {
     fn is_prefix_of(self, input: &I) -> bool;
+
+    fn is_prefix_of_ignore_ascii_case(self, input: &I) -> bool;
 }
 
-unsafe impl<'i, T, I> Prefix<I> for &T
+unsafe impl<T, I> Prefix<I> for &T
 where
     T: Prefix<I>,
 {
@@ -14,6 +20,11 @@ where
     fn is_prefix_of(self, input: &I) -> bool {
         (*self).is_prefix_of(input)
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &I) -> bool {
+        (*self).is_prefix_of_ignore_ascii_case(input)
+    }
 }
 
 unsafe impl<'i> Prefix<Bytes<'i>> for u8 {
@@ -21,6 +32,11 @@ unsafe impl<'i> Prefix<Bytes<'i>> for u8 {
     fn is_prefix_of(self, input: &Bytes<'i>) -> bool {
         input.as_dangerous().starts_with(&[self])
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &Bytes<'i>) -> bool {
+        starts_with_ignore_ascii_case(input.as_dangerous(), &[self])
+    }
 }
 
 unsafe impl<'i> Prefix<String<'i>> for char {
@@ -31,6 +47,14 @@ unsafe impl<'i> Prefix<String<'i>> for char {
             None => false,
         }
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &String<'i>) -> bool {
+        match input.as_dangerous().chars().next() {
+            Some(c) => c.eq_ignore_ascii_case(&self),
+            None => false,
+        }
+    }
 }
 
 unsafe impl<'i> Prefix<Bytes<'i>> for char {
@@ -39,6 +63,12 @@ unsafe impl<'i> Prefix<Bytes<'i>> for char {
         let bytes = CharBytes::from(self);
         input.as_dangerous().starts_with(bytes.as_bytes())
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &Bytes<'i>) -> bool {
+        let bytes = CharBytes::from(self);
+        starts_with_ignore_ascii_case(input.as_dangerous(), bytes.as_bytes())
+    }
 }
 
 unsafe impl<'i> Prefix<Bytes<'i>> for &[u8] {
@@ -46,6 +76,11 @@ unsafe impl<'i> Prefix<Bytes<'i>> for &[u8] {
     fn is_prefix_of(self, input: &Bytes<'i>) -> bool {
         input.as_dangerous().starts_with(self)
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &Bytes<'i>) -> bool {
+        starts_with_ignore_ascii_case(input.as_dangerous(), self)
+    }
 }
 
 unsafe impl<'i> Prefix<String<'i>> for &str {
@@ -53,6 +88,11 @@ unsafe impl<'i> Prefix<String<'i>> for &str {
     fn is_prefix_of(self, input: &String<'i>) -> bool {
         input.as_dangerous().starts_with(self)
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &String<'i>) -> bool {
+        starts_with_ignore_ascii_case(input.as_dangerous().as_bytes(), self.as_bytes())
+    }
 }
 
 unsafe impl<'i> Prefix<Bytes<'i>> for &str {
@@ -60,6 +100,11 @@ unsafe impl<'i> Prefix<Bytes<'i>> for &str {
     fn is_prefix_of(self, input: &Bytes<'i>) -> bool {
         input.as_dangerous().starts_with(self.as_bytes())
     }
+
+    #[inline(always)]
+    fn is_prefix_of_ignore_ascii_case(self, input: &Bytes<'i>) -> bool {
+        starts_with_ignore_ascii_case(input.as_dangerous(), self.as_bytes())
+    }
 }
 
 macro_rules! impl_array_prefix {
@@ -70,9 +115,22 @@ macro_rules! impl_array_prefix {
                 fn is_prefix_of(self, input: &Bytes<'i>) -> bool {
                     input.as_dangerous().starts_with(&self[..])
                 }
+
+                #[inline(always)]
+                fn is_prefix_of_ignore_ascii_case(self, input: &Bytes<'i>) -> bool {
+                    starts_with_ignore_ascii_case(input.as_dangerous(), &self[..])
+                }
             }
         )*
     };
 }
 
 for_common_array_sizes!(impl_array_prefix);
+
+#[inline(always)]
+fn starts_with_ignore_ascii_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    match bytes.get(..prefix.len()) {
+        Some(head) => head.eq_ignore_ascii_case(prefix),
+        None => false,
+    }
+}
@@ -0,0 +1,67 @@
+/// Control characters allowed when validating printable text.
+///
+/// By default all control characters are rejected. Used with
+/// [`String::to_dangerous_printable_str()`](crate::String::to_dangerous_printable_str()).
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{AllowedControls, Expected};
+///
+/// let input = dangerous::input("one\ttwo\n");
+/// let allowed = AllowedControls::none().tab(true).newline(true);
+///
+/// assert!(input.to_dangerous_printable_str::<Expected<'_>>(allowed).is_ok());
+/// assert!(input.to_dangerous_printable_str::<Expected<'_>>(AllowedControls::none()).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllowedControls {
+    tab: bool,
+    newline: bool,
+}
+
+impl AllowedControls {
+    /// Allows no control characters.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            tab: false,
+            newline: false,
+        }
+    }
+
+    /// Allow a horizontal tab (`\t`).
+    #[must_use]
+    pub const fn tab(mut self, value: bool) -> Self {
+        self.tab = value;
+        self
+    }
+
+    /// Allow a line feed (`\n`) and carriage return (`\r`).
+    #[must_use]
+    pub const fn newline(mut self, value: bool) -> Self {
+        self.newline = value;
+        self
+    }
+
+    /// Returns `true` if the char is a control or bidi control character not
+    /// allowed.
+    pub(crate) fn rejects(self, c: char) -> bool {
+        match c {
+            '\t' => !self.tab,
+            '\n' | '\r' => !self.newline,
+            c => c.is_control() || is_bidi_control(c),
+        }
+    }
+}
+
+/// Returns `true` if the char has the Unicode `Bidi_Control` property.
+///
+/// These invisibly reorder the display of text and can make source code or
+/// logs read differently to how they are parsed (CVE-2021-42574).
+pub(crate) fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
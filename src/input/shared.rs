@@ -0,0 +1,184 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::display::InputDisplay;
+use crate::fmt;
+
+use super::{Bound, Bytes, Input, IntoInput};
+
+/// Reference counted [`Bytes`] that can outlive the buffer they were parsed
+/// from.
+///
+/// `SharedBytes` is backed by an [`Arc<[u8]>`](Arc) and can be sent across
+/// threads. Input is read from it by borrowing with [`SharedBytes::input()`]
+/// or [`dangerous::input()`], and any sub-input parsed can be turned back into
+/// an owned `SharedBytes` with [`SharedBytes::slice_ref()`], sharing the same
+/// underlying buffer.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid, SharedBytes};
+///
+/// let shared = SharedBytes::from(b"hello world".to_vec());
+///
+/// let result: Result<_, Invalid> = dangerous::input(&shared).read_all(|r| {
+///     let hello = r.take_until_consume(b' ')?;
+///     Ok((hello, r.take_remaining()))
+/// });
+/// let (hello, world) = result.unwrap();
+/// let world = shared.slice_ref(&world).unwrap();
+///
+/// // The shared sub-input can be moved to another thread.
+/// std::thread::spawn(move || {
+///     assert_eq!(world.as_dangerous(), b"world");
+/// })
+/// .join()
+/// .unwrap();
+/// # assert_eq!(hello, b"hello"[..]);
+/// ```
+///
+/// [`dangerous::input()`]: crate::input()
+#[derive(Clone)]
+#[must_use = "input must be consumed"]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct SharedBytes {
+    buf: Arc<[u8]>,
+    range: Range<usize>,
+    bound: Bound,
+}
+
+impl SharedBytes {
+    /// Returns the underlying byte slice length.
+    #[must_use]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns `true` if the underlying byte slice length is zero.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns the underlying byte slice.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_dangerous(&self) -> &[u8] {
+        &self.buf[self.range.clone()]
+    }
+
+    /// Returns [`Bytes`] borrowed from `self` with the same [`Bound`].
+    #[inline(always)]
+    pub fn input(&self) -> Bytes<'_> {
+        Bytes::new(self.as_dangerous(), self.bound)
+    }
+
+    /// Returns `self` as bound, see [`Input::into_bound()`].
+    pub fn into_bound(mut self) -> Self {
+        self.bound = Bound::force_close();
+        self
+    }
+
+    /// Returns the sub-input as `SharedBytes` sharing the same buffer if it
+    /// is within `self` and `None` if not.
+    ///
+    /// The returned `SharedBytes` carries the [`Bound`] of `sub`.
+    #[must_use]
+    pub fn slice_ref<'i>(&self, sub: &impl Input<'i>) -> Option<SharedBytes> {
+        sub.span_of(&self.input()).map(|span| SharedBytes {
+            buf: self.buf.clone(),
+            range: (self.range.start + span.start)..(self.range.start + span.end),
+            bound: sub.bound(),
+        })
+    }
+
+    /// Returns the `range` of `self` as `SharedBytes` sharing the same buffer,
+    /// and `None` if the range is out of bounds.
+    ///
+    /// The returned `SharedBytes` is bound at its end.
+    #[must_use]
+    pub fn slice(&self, range: Range<usize>) -> Option<SharedBytes> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        Some(SharedBytes {
+            buf: self.buf.clone(),
+            range: (self.range.start + range.start)..(self.range.start + range.end),
+            bound: self.bound.close_end(),
+        })
+    }
+}
+
+impl From<Arc<[u8]>> for SharedBytes {
+    fn from(buf: Arc<[u8]>) -> Self {
+        Self {
+            range: 0..buf.len(),
+            buf,
+            bound: Bound::Start,
+        }
+    }
+}
+
+impl From<Vec<u8>> for SharedBytes {
+    fn from(buf: Vec<u8>) -> Self {
+        Self::from(Arc::<[u8]>::from(buf))
+    }
+}
+
+impl From<&[u8]> for SharedBytes {
+    fn from(buf: &[u8]) -> Self {
+        Self::from(Arc::<[u8]>::from(buf))
+    }
+}
+
+impl<'i> IntoInput<'i> for &'i SharedBytes {
+    type Input = Bytes<'i>;
+
+    #[inline(always)]
+    fn into_input(self) -> Self::Input {
+        self.input()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Equality
+
+impl PartialEq for SharedBytes {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_dangerous() == other.as_dangerous()
+    }
+}
+
+impl PartialEq<[u8]> for SharedBytes {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_dangerous() == other
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Formatting
+
+impl fmt::Debug for SharedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = self.input();
+        let display = InputDisplay::from_formatter(&input, f);
+        f.debug_struct("SharedBytes")
+            .field("bound", &self.bound)
+            .field("value", &display)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for SharedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.input(), f)
+    }
+}
@@ -0,0 +1,157 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use super::{Input, Pattern, PrivateExt};
+
+/// An iterator over [`Input`] separated by a [`Pattern`].
+///
+/// Created with [`Input::split()`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Split<'i, I, P> {
+    remaining: Option<I>,
+    pattern: P,
+    lifetime: PhantomData<&'i ()>,
+}
+
+impl<I, P> Split<'_, I, P> {
+    pub(crate) fn new(input: I, pattern: P) -> Self {
+        Self {
+            remaining: Some(input),
+            pattern,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'i, I, P> Iterator for Split<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        let input = self.remaining.take()?;
+        match input.clone().split_until_consume_opt(self.pattern) {
+            // Stop on an empty match at the start of the input so we always
+            // make progress.
+            Some((_, tail)) if tail.byte_len() == input.byte_len() => Some(input),
+            Some((head, tail)) => {
+                self.remaining = Some(tail);
+                Some(head)
+            }
+            None => Some(input),
+        }
+    }
+}
+
+impl<'i, I, P> FusedIterator for Split<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+}
+
+/// An iterator over at most `n` [`Input`] separated by a [`Pattern`].
+///
+/// Created with [`Input::splitn()`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitN<'i, I, P> {
+    inner: Split<'i, I, P>,
+    count: usize,
+}
+
+impl<I, P> SplitN<'_, I, P> {
+    pub(crate) fn new(input: I, count: usize, pattern: P) -> Self {
+        Self {
+            inner: Split::new(input, pattern),
+            count,
+        }
+    }
+}
+
+impl<'i, I, P> Iterator for SplitN<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.remaining.take()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+impl<'i, I, P> FusedIterator for SplitN<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+}
+
+/// An iterator over the lines of [`Input`].
+///
+/// Created with [`Bytes::lines()`] or [`String::lines()`].
+///
+/// [`Bytes::lines()`]: crate::Bytes::lines()
+/// [`String::lines()`]: crate::String::lines()
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Lines<'i, I> {
+    remaining: Option<I>,
+    lifetime: PhantomData<&'i ()>,
+}
+
+impl<I> Lines<'_, I> {
+    pub(crate) fn new(input: I) -> Self {
+        Self {
+            remaining: Some(input),
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'i, I> Iterator for Lines<'i, I>
+where
+    I: Input<'i>,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        let input = self.remaining.take()?;
+        if input.is_empty() {
+            return None;
+        }
+        let bytes = input.as_dangerous_bytes();
+        match Pattern::find_match(b'\n', &input.clone().into_bytes()) {
+            Some((index, _)) => {
+                // SAFETY: Pattern guarantees it returns valid indexes and as
+                // `\n` and `\r` are ASCII, they are always on a char boundary.
+                let (line, tail) = unsafe { input.split_at_byte_unchecked(index) };
+                let (_, tail) = unsafe { tail.split_at_byte_unchecked(1) };
+                let line = if index > 0 && bytes[index - 1] == b'\r' {
+                    unsafe { line.split_at_byte_unchecked(index - 1).0 }
+                } else {
+                    line
+                };
+                self.remaining = Some(tail);
+                Some(line)
+            }
+            None => Some(input),
+        }
+    }
+}
+
+impl<'i, I> FusedIterator for Lines<'i, I> where I: Input<'i> {}
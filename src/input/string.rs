@@ -1,11 +1,16 @@
 use core::str;
 
+#[cfg(feature = "unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "unicode")]
+use unicode_xid::UnicodeXID;
+
 use crate::display::InputDisplay;
 use crate::error::{ExpectedContext, ExpectedLength, ExpectedValid, Length};
 use crate::fmt;
 use crate::util::{slice, utf8};
 
-use super::{Bound, Bytes, Input, MaybeString, Private};
+use super::{AllowedControls, Bound, Bytes, Input, Lines, MaybeString, Private};
 
 /// UTF-8 [`Input`].
 #[derive(Clone)]
@@ -81,6 +86,38 @@ impl<'i> String<'i> {
         }
     }
 
+    /// Returns the underlying string slice if it has no control characters
+    /// other than those allowed, and no bidi control characters.
+    ///
+    /// Rejected are C0 and C1 controls including `NUL` and `DEL`, along with
+    /// chars with the Unicode `Bidi_Control` property such as `U+202E` (RIGHT-TO-LEFT
+    /// OVERRIDE) used in Trojan Source attacks.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] with a span of the first rejected char.
+    pub fn to_dangerous_printable_str<E>(&self, allowed: AllowedControls) -> Result<&'i str, E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        let s = self.as_dangerous();
+        match s.char_indices().find(|(_, c)| allowed.rejects(*c)) {
+            Some((i, c)) => Err(E::from(ExpectedValid {
+                span: &s.as_bytes()[i..i + c.len_utf8()],
+                input: self.clone().into_maybe_string(),
+                context: ExpectedContext {
+                    operation: "convert input to printable str",
+                    expected: "printable string",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            })),
+            None => Ok(s),
+        }
+    }
+
     /// Decodes [`Bytes`] into a UTF-8 [`String`].
     ///
     /// # Errors
@@ -104,6 +141,265 @@ impl<'i> String<'i> {
     pub unsafe fn from_utf8_unchecked(utf8: Bytes<'i>) -> Self {
         Self { utf8 }
     }
+
+    /// Returns an iterator over the lines of the input.
+    ///
+    /// Lines are split on `\n` with a trailing `\r` removed. A trailing empty
+    /// line is not yielded. Each complete line is bound at its end while the
+    /// last line carries the bound of `self`, so a partial line of unbound
+    /// input can be distinguished from a complete one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let mut lines = dangerous::input("one\r\ntwo\nthree").lines();
+    ///
+    /// assert_eq!(lines.next().unwrap(), "one"[..]);
+    /// assert_eq!(lines.next().unwrap(), "two"[..]);
+    ///
+    /// let last = lines.next().unwrap();
+    /// assert_eq!(last, "three"[..]);
+    /// assert!(!last.is_bound());
+    /// assert!(lines.next().is_none());
+    /// ```
+    #[inline]
+    pub fn lines(self) -> Lines<'i, Self> {
+        Lines::new(self)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Unicode
+
+#[cfg(feature = "unicode")]
+impl<'i> String<'i> {
+    /// Splits the input after `n` extended grapheme clusters.
+    ///
+    /// If the last grapheme cluster reaches the end of unbound input it may
+    /// be extended by more input, so more is required.
+    pub(crate) fn split_graphemes<E>(
+        self,
+        n: usize,
+        operation: &'static str,
+    ) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        let s = self.as_dangerous();
+        let mut graphemes = s.grapheme_indices(true).map(|(i, g)| i + g.len());
+        let mut mid = 0;
+        let mut taken = 0;
+        while taken < n {
+            match graphemes.next() {
+                Some(end) => mid = end,
+                None => break,
+            }
+            taken += 1;
+        }
+        if taken == n && (mid < s.len() || n == 0 || self.is_bound()) {
+            // SAFETY: grapheme boundaries are always char boundaries.
+            Ok(unsafe { self.split_at_byte_unchecked(mid) })
+        } else {
+            Err(E::from(ExpectedLength {
+                len: Length::AtLeast(s.len() + (n - taken).max(1)),
+                span: s.as_bytes(),
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "complete grapheme clusters",
+                },
+            }))
+        }
+    }
+
+    /// Splits an identifier from the front of the input as defined by
+    /// UAX #31, a `XID_Start` char followed by any `XID_Continue` chars.
+    pub(crate) fn split_xid_identifier<E>(self, operation: &'static str) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let s = self.as_dangerous();
+        match s.chars().next() {
+            None => Err(E::from(ExpectedLength {
+                len: Length::AtLeast(1),
+                span: s.as_bytes(),
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "identifier",
+                },
+            })),
+            Some(c) if !c.is_xid_start() => Err(E::from(ExpectedValid {
+                span: &s.as_bytes()[..c.len_utf8()],
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "identifier start",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            })),
+            Some(_) => {
+                let mid = s
+                    .char_indices()
+                    .find(|(_, c)| !c.is_xid_continue())
+                    .map_or(s.len(), |(i, _)| i);
+                // SAFETY: the index is from a char boundary or the end.
+                Ok(unsafe { self.split_at_byte_unchecked(mid) })
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Unicode normalization
+
+#[cfg(feature = "unicode-normalization")]
+impl<'i> String<'i> {
+    /// Returns `true` if the string is in Normalization Form C (NFC).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert!(dangerous::input("caf\u{e9}").is_nfc());
+    /// assert!(!dangerous::input("cafe\u{301}").is_nfc());
+    /// ```
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub fn is_nfc(&self) -> bool {
+        unicode_normalization::is_nfc(self.as_dangerous())
+    }
+
+    /// Returns the underlying string slice if it is in Normalization Form C
+    /// (NFC).
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] with a span of the first char not in NFC
+    /// along with any combining marks that follow it.
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-normalization")))]
+    pub fn to_dangerous_nfc_str<E>(&self) -> Result<&'i str, E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        use unicode_normalization::char::canonical_combining_class;
+        use unicode_normalization::UnicodeNormalization;
+
+        let s = self.as_dangerous();
+        if self.is_nfc() {
+            return Ok(s);
+        }
+        let start = s
+            .char_indices()
+            .zip(s.nfc())
+            .find(|((_, c), nfc)| c != nfc)
+            .map_or(s.len(), |((i, _), _)| i);
+        let end = s[start..]
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| canonical_combining_class(*c) == 0)
+            .map_or(s.len(), |(i, _)| start + i);
+        Err(E::from(ExpectedValid {
+            span: &s.as_bytes()[start..end],
+            input: self.clone().into_maybe_string(),
+            context: ExpectedContext {
+                operation: "convert input to nfc str",
+                expected: "nfc normalized string",
+            },
+            #[cfg(feature = "retry")]
+            retry_requirement: None,
+        }))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Unicode security
+
+#[cfg(feature = "unicode-security")]
+impl<'i> String<'i> {
+    /// Returns `true` if the string is confusable with `other`, that is both
+    /// have the same UTS #39 skeleton.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // The first `а` is CYRILLIC SMALL LETTER A.
+    /// assert!(dangerous::input("p\u{430}ypal").is_confusable_with("paypal"));
+    /// assert!(!dangerous::input("paypal").is_confusable_with("paypals"));
+    /// ```
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-security")))]
+    pub fn is_confusable_with(&self, other: &str) -> bool {
+        use unicode_security::confusable_detection::skeleton;
+
+        skeleton(self.as_dangerous()).eq(skeleton(other))
+    }
+
+    /// Returns `true` if the string is single-script as defined by UTS #39.
+    ///
+    /// Chars common to all scripts such as digits and punctuation are
+    /// allowed alongside any single script.
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-security")))]
+    pub fn is_single_script(&self) -> bool {
+        use unicode_security::MixedScript;
+
+        self.as_dangerous().is_single_script()
+    }
+
+    /// Returns the underlying string slice if it is single-script as defined
+    /// by UTS #39, rejecting mixed-script strings used in homograph attacks.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] with a span of the first char that does not
+    /// share a script with the chars before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Expected;
+    /// use dangerous::error::Details;
+    ///
+    /// let error = dangerous::input("p\u{430}ypal")
+    ///     .to_dangerous_single_script_str::<Expected<'_>>()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(error.span(), "\u{430}".as_bytes());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode-security")))]
+    pub fn to_dangerous_single_script_str<E>(&self) -> Result<&'i str, E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        use unicode_security::mixed_script::AugmentedScriptSet;
+
+        let s = self.as_dangerous();
+        let mut set = AugmentedScriptSet::default();
+        for (i, c) in s.char_indices() {
+            set.intersect_with(AugmentedScriptSet::for_char(c));
+            if set.is_empty() {
+                return Err(E::from(ExpectedValid {
+                    span: &s.as_bytes()[i..i + c.len_utf8()],
+                    input: self.clone().into_maybe_string(),
+                    context: ExpectedContext {
+                        operation: "convert input to single script str",
+                        expected: "single script string",
+                    },
+                    #[cfg(feature = "retry")]
+                    retry_requirement: None,
+                }));
+            }
+        }
+        Ok(s)
+    }
 }
 
 impl<'i> Input<'i> for String<'i> {
@@ -160,7 +456,7 @@ impl<'i> Private<'i> for String<'i> {
         let string = self.as_dangerous();
         let iter = &mut string.chars();
         if iter.nth(mid.saturating_sub(1)).is_some() {
-            let byte_mid = string.as_bytes().len() - iter.as_str().as_bytes().len();
+            let byte_mid = string.len() - iter.as_str().len();
             // SAFETY: we take byte_mid as the difference between the parent
             // string and the remaining string left over from the char iterator.
             // This means both the index can only ever be valid and the bytes in
@@ -184,28 +480,28 @@ impl<'i> Private<'i> for String<'i> {
 ///////////////////////////////////////////////////////////////////////////////
 // Equality
 
-impl<'i> PartialEq for String<'i> {
+impl PartialEq for String<'_> {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         self.as_dangerous() == other.as_dangerous()
     }
 }
 
-impl<'i> PartialEq<str> for String<'i> {
+impl PartialEq<str> for String<'_> {
     #[inline(always)]
     fn eq(&self, other: &str) -> bool {
         self.as_dangerous() == other
     }
 }
 
-impl<'i> PartialEq<str> for &String<'i> {
+impl PartialEq<str> for &String<'_> {
     #[inline(always)]
     fn eq(&self, other: &str) -> bool {
         self.as_dangerous() == other
     }
 }
 
-impl<'i> PartialEq<&str> for String<'i> {
+impl PartialEq<&str> for String<'_> {
     #[inline(always)]
     fn eq(&self, other: &&str) -> bool {
         self.as_dangerous() == *other
@@ -222,7 +518,7 @@ impl<'i> PartialEq<String<'i>> for str {
 ///////////////////////////////////////////////////////////////////////////////
 // Formatting
 
-impl<'i> fmt::Debug for String<'i> {
+impl fmt::Debug for String<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let display = InputDisplay::from_formatter(self, f).str_hint(true);
         f.debug_struct("String")
@@ -232,13 +528,13 @@ impl<'i> fmt::Debug for String<'i> {
     }
 }
 
-impl<'i> fmt::DisplayBase for String<'i> {
+impl fmt::DisplayBase for String<'_> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
         self.display().fmt(w)
     }
 }
 
-impl<'i> fmt::Display for String<'i> {
+impl fmt::Display for String<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         InputDisplay::from_formatter(self, f).str_hint(true).fmt(f)
     }
@@ -248,7 +544,7 @@ impl<'i> fmt::Display for String<'i> {
 // Zc
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for String<'i> {}
+unsafe impl zc::NoInteriorMut for String<'_> {}
 
 #[cfg(feature = "zc")]
-unsafe impl<'i> zc::NoInteriorMut for MaybeString<'i> {}
+unsafe impl zc::NoInteriorMut for MaybeString<'_> {}
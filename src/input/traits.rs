@@ -9,11 +9,13 @@ use crate::error::{
     OperationContext, Value, WithContext,
 };
 use crate::fmt::{Debug, Display, DisplayBase};
+#[cfg(feature = "aho-corasick")]
+use crate::input::pattern::AnyLiteral;
 use crate::input::pattern::Pattern;
-use crate::reader::Reader;
+use crate::reader::{Endian, Reader};
 use crate::util::slice;
 
-use super::{Bound, Bytes, MaybeString, Prefix, String};
+use super::{Bound, Bytes, MaybeString, Prefix, Split, SplitN, String};
 
 /// An [`Input`] is an immutable wrapper around bytes to be processed.
 ///
@@ -54,6 +56,7 @@ pub trait Input<'i>: Private<'i> {
     /// ```
     ///
     /// [`RetryRequirement`]: crate::error::RetryRequirement
+    #[must_use]
     fn into_bound(self) -> Self;
 
     /// Consumes `self` into [`Bytes`].
@@ -137,6 +140,57 @@ pub trait Input<'i>: Private<'i> {
         }
     }
 
+    /// Returns an iterator over the input separated by `pattern`.
+    ///
+    /// Each input yielded before a match of `pattern` is bound at its end.
+    /// The last input yielded carries the bound of `self`, so a trailing
+    /// partial item of unbound input can be distinguished from a complete one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input(b"a,b,c");
+    /// let mut items = input.clone().split(b',');
+    ///
+    /// assert_eq!(items.next().unwrap(), b"a"[..]);
+    /// assert_eq!(items.next().unwrap().span_of(&input), Some(2..3));
+    /// assert_eq!(items.next().unwrap(), b"c"[..]);
+    /// assert!(items.next().is_none());
+    /// ```
+    #[inline]
+    fn split<P>(self, pattern: P) -> Split<'i, Self, P>
+    where
+        P: Pattern<Self> + Copy,
+    {
+        Split::new(self, pattern)
+    }
+
+    /// Returns an iterator over at most `n` inputs separated by `pattern`.
+    ///
+    /// The last input yielded contains the remaining input, see
+    /// [`Input::split()`] for details on how each input yielded is bound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let mut items = dangerous::input("key=value=1").splitn(2, '=');
+    ///
+    /// assert_eq!(items.next().unwrap(), "key"[..]);
+    /// assert_eq!(items.next().unwrap(), "value=1"[..]);
+    /// assert!(items.next().is_none());
+    /// ```
+    #[inline]
+    fn splitn<P>(self, n: usize, pattern: P) -> SplitN<'i, Self, P>
+    where
+        P: Pattern<Self> + Copy,
+    {
+        SplitN::new(self, n, pattern)
+    }
+
     /// Create a reader with the expectation all of the input is read.
     ///
     /// # Errors
@@ -326,7 +380,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         E: From<ExpectedValue<'i>>,
         P: Prefix<Self> + Into<Value<'i>>,
     {
-        match self.clone().split_prefix_opt(&prefix) {
+        match self.clone().split_prefix_opt(prefix) {
             (Some(head), tail) => Ok((head, tail)),
             (None, unmatched) => {
                 let bytes = unmatched.as_dangerous_bytes();
@@ -334,7 +388,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
                 let actual = if bytes.len() > prefix_len {
                     &bytes[..prefix_len]
                 } else {
-                    &bytes
+                    bytes
                 };
                 Err(E::from(ExpectedValue {
                     actual,
@@ -349,6 +403,78 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         }
     }
 
+    /// Splits a prefix from the input if it is present, ignoring ASCII case.
+    #[inline(always)]
+    fn split_prefix_ignore_ascii_case_opt<P>(self, prefix: P) -> (Option<Self>, Self)
+    where
+        P: Prefix<Self>,
+    {
+        if prefix.is_prefix_of_ignore_ascii_case(&self) {
+            // SAFETY: we just validated that prefix is within the input so its
+            // length is a valid index. The prefix only differs from the input
+            // by ASCII case, so the index is on a char boundary.
+            let (head, tail) = unsafe { self.split_at_byte_unchecked(prefix.byte_len()) };
+            (Some(head), tail)
+        } else {
+            (None, self)
+        }
+    }
+
+    /// Splits a prefix from the input if it is present, ignoring ASCII case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input is shorter than the prefix but
+    /// matches so far and [`ExpectedValue`] if the input does not have the
+    /// prefix.
+    #[inline(always)]
+    fn split_prefix_ignore_ascii_case<P, E>(
+        self,
+        prefix: P,
+        operation: &'static str,
+    ) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedValue<'i>>,
+        E: From<ExpectedLength<'i>>,
+        P: Prefix<Self> + Into<Value<'i>>,
+    {
+        match self.clone().split_prefix_ignore_ascii_case_opt(prefix) {
+            (Some(head), tail) => Ok((head, tail)),
+            (None, unmatched) => {
+                let bytes = unmatched.as_dangerous_bytes();
+                let prefix_len = prefix.byte_len();
+                let expected: Value<'i> = prefix.into();
+                if bytes.len() < prefix_len
+                    && expected.as_bytes()[..bytes.len()].eq_ignore_ascii_case(bytes)
+                {
+                    return Err(E::from(ExpectedLength {
+                        len: Length::AtLeast(prefix_len),
+                        span: bytes,
+                        input: self.into_maybe_string(),
+                        context: ExpectedContext {
+                            operation,
+                            expected: "enough input",
+                        },
+                    }));
+                }
+                let actual = if bytes.len() > prefix_len {
+                    &bytes[..prefix_len]
+                } else {
+                    bytes
+                };
+                Err(E::from(ExpectedValue {
+                    actual,
+                    expected,
+                    input: self.into_maybe_string(),
+                    context: ExpectedContext {
+                        operation,
+                        expected: "exact value ignoring ASCII case",
+                    },
+                }))
+            }
+        }
+    }
+
     /// Splits at a pattern in the input if it is present.
     #[inline(always)]
     fn split_until_opt<P>(self, pattern: P) -> Option<(Self, Self)>
@@ -425,6 +551,52 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
             })
     }
 
+    /// Splits the input up to the first of any of the literals, returning
+    /// the index of the literal that matched and optionally consuming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if none of the literals could be found.
+    #[cfg(feature = "aho-corasick")]
+    fn split_until_literal<E>(
+        self,
+        literals: &AnyLiteral,
+        consume: bool,
+        operation: &'static str,
+    ) -> Result<((Self, usize), Self), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        match literals.find_literal(&self.clone().into_maybe_string()) {
+            Some((index, len, literal)) => {
+                // SAFETY: AnyLiteral guarantees it returns valid indexes.
+                let (head, tail) = unsafe { self.split_at_byte_unchecked(index) };
+                let tail = if consume {
+                    // SAFETY: the match ends at `index + len` within the
+                    // input on a char boundary, so `len` is within `tail`.
+                    unsafe { tail.split_at_byte_unchecked(len).1 }
+                } else {
+                    tail
+                };
+                Ok(((head, literal), tail))
+            }
+            None => Err(E::from(ExpectedValid {
+                span: self.as_dangerous_bytes(),
+                #[cfg(feature = "retry")]
+                retry_requirement: if self.is_bound() {
+                    None
+                } else {
+                    crate::error::RetryRequirement::new(1)
+                },
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "literal match",
+                },
+            })),
+        }
+    }
+
     /// Splits the input up to when the provided function returns `false`.
     #[inline(always)]
     fn split_while_opt<P>(self, pattern: P) -> Option<(Self, Self)>
@@ -466,11 +638,11 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
 
     /// Splits the input at what was read and what was remaining.
     #[inline(always)]
-    fn split_consumed<F, E>(self, f: F) -> (Self, Self)
+    fn split_consumed<F, E>(self, f: F, endian: Endian) -> (Self, Self)
     where
         F: FnOnce(&mut Reader<'i, E, Self>),
     {
-        let mut reader = Reader::new(self.clone());
+        let mut reader = Reader::with_endian_of(self.clone(), endian);
         // Consume input.
         f(&mut reader);
         // We take the remaining input.
@@ -498,12 +670,17 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     ///
     /// Returns an error from the provided function if it fails.
     #[inline(always)]
-    fn try_split_consumed<F, E>(self, f: F, operation: &'static str) -> Result<(Self, Self), E>
+    fn try_split_consumed<F, E>(
+        self,
+        f: F,
+        endian: Endian,
+        operation: &'static str,
+    ) -> Result<(Self, Self), E>
     where
         E: WithContext<'i>,
         F: FnOnce(&mut Reader<'i, E, Self>) -> Result<(), E>,
     {
-        let mut reader = Reader::new(self.clone());
+        let mut reader = Reader::with_endian_of(self.clone(), endian);
         // Consume input.
         reader.context(OperationContext(operation), f)?;
         // We take the remaining input.
@@ -534,6 +711,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     fn split_expect<F, T, E>(
         self,
         f: F,
+        endian: Endian,
         expected: &'static str,
         operation: &'static str,
     ) -> Result<(T, Self), E>
@@ -542,10 +720,10 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         F: FnOnce(&mut Reader<'i, E, Self>) -> Option<T>,
     {
         let context = ExpectedContext {
-            expected,
             operation,
+            expected,
         };
-        let mut reader = Reader::new(self.clone());
+        let mut reader = Reader::with_endian_of(self.clone(), endian);
         if let Some(ok) = f(&mut reader) {
             Ok((ok, reader.take_remaining()))
         } else {
@@ -571,6 +749,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     fn try_split_expect<F, T, E>(
         self,
         f: F,
+        endian: Endian,
         expected: &'static str,
         operation: &'static str,
     ) -> Result<(T, Self), E>
@@ -580,10 +759,10 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         F: FnOnce(&mut Reader<'i, E, Self>) -> Result<Option<T>, E>,
     {
         let context = ExpectedContext {
-            expected,
             operation,
+            expected,
         };
-        let mut reader = Reader::new(self.clone());
+        let mut reader = Reader::with_endian_of(self.clone(), endian);
         match reader.context(context, f) {
             Ok(Some(ok)) => Ok((ok, reader.take_remaining())),
             Ok(None) => {
@@ -612,6 +791,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     fn try_split_expect_erased<F, T, R, E>(
         self,
         f: F,
+        endian: Endian,
         expected: &'static str,
         operation: &'static str,
     ) -> Result<(T, Self), E>
@@ -621,7 +801,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         F: FnOnce(&mut Reader<'i, E, Self>) -> Result<T, R>,
         R: ToRetryRequirement,
     {
-        let mut reader = Reader::new(self.clone());
+        let mut reader = Reader::with_endian_of(self.clone(), endian);
         match f(&mut reader) {
             Ok(ok) => Ok((ok, reader.take_remaining())),
             Err(err) => {
@@ -631,8 +811,8 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
                     span,
                     input: self.into_maybe_string(),
                     context: ExpectedContext {
-                        expected,
                         operation,
+                        expected,
                     },
                     retry_requirement: err.to_retry_requirement(),
                 }))
@@ -646,6 +826,9 @@ impl<'i, T> PrivateExt<'i> for T where T: Input<'i> {}
 ///////////////////////////////////////////////////////////////////////////////
 // BytesLength
 
+/// # Safety
+///
+/// `byte_len` must return the exact length of `self` in bytes.
 pub unsafe trait BytesLength: Copy {
     fn byte_len(self) -> usize;
 }
@@ -684,7 +867,7 @@ unsafe impl BytesLength for &[u8] {
 unsafe impl BytesLength for &str {
     #[inline(always)]
     fn byte_len(self) -> usize {
-        self.as_bytes().len()
+        self.len()
     }
 }
 
@@ -742,6 +925,26 @@ impl<'i> IntoInput<'i> for &'i str {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl<'i> IntoInput<'i> for &'i bytes::Bytes {
+    type Input = Bytes<'i>;
+
+    #[inline(always)]
+    fn into_input(self) -> Self::Input {
+        Bytes::new(self, Bound::Start)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'i> IntoInput<'i> for &'i bytes::BytesMut {
+    type Input = Bytes<'i>;
+
+    #[inline(always)]
+    fn into_input(self) -> Self::Input {
+        Bytes::new(self, Bound::Start)
+    }
+}
+
 macro_rules! impl_array_into_input {
     ($($n:expr),*) => {
         $(
@@ -0,0 +1,290 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::display::InputDisplay;
+use crate::error::{
+    ExpectedContext, ExpectedLength, ExpectedValid, Length, OperationContext, WithContext,
+};
+use crate::fmt;
+use crate::reader::{Endian, Utf16Reader};
+use crate::util::utf16::{self, InvalidUnit};
+
+use super::{Bound, Bytes, Input, MaybeString};
+
+/// UTF-16 encoded input in a given byte order.
+///
+/// `Utf16` is created from [`Bytes`] with [`Bytes::into_utf16()`] and is read
+/// with a [`Utf16Reader`], which validates surrogate pairs as it decodes each
+/// `char`.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Endian, Expected, Input};
+///
+/// let input = dangerous::input(b"h\x00i\x00!\x00").into_utf16(Endian::Little);
+/// let result: Result<_, Expected<'_>> = input.read_all(|r| {
+///     let greeting = r.take_while(|c| c.is_alphabetic())?;
+///     r.consume("!")?;
+///     Ok(greeting)
+/// });
+/// let greeting = result.unwrap();
+///
+/// assert_eq!(greeting, *"hi");
+/// assert_eq!(greeting.to_string(), r#""hi""#);
+/// ```
+#[derive(Clone)]
+#[must_use = "input must be consumed"]
+pub struct Utf16<'i> {
+    bytes: &'i [u8],
+    endian: Endian,
+    bound: Bound,
+}
+
+impl<'i> Utf16<'i> {
+    #[inline(always)]
+    pub(crate) fn new(bytes: &'i [u8], endian: Endian, bound: Bound) -> Self {
+        Self {
+            bytes,
+            endian,
+            bound,
+        }
+    }
+
+    /// Returns the underlying byte slice length.
+    #[must_use]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the underlying byte slice length is zero.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the underlying byte slice.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_dangerous(&self) -> &'i [u8] {
+        self.bytes
+    }
+
+    /// Returns the byte order of the UTF-16 code units.
+    #[must_use]
+    #[inline(always)]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Returns the inner [`Bound`] of the input.
+    #[inline(always)]
+    pub fn bound(&self) -> Bound {
+        self.bound
+    }
+
+    /// Returns `self` as bound, see [`Input::into_bound()`].
+    #[inline(always)]
+    pub fn into_bound(mut self) -> Self {
+        self.bound = Bound::force_close();
+        self
+    }
+
+    /// Returns the underlying bytes as [`Bytes`].
+    #[inline(always)]
+    pub fn into_bytes(self) -> Bytes<'i> {
+        Bytes::new(self.bytes, self.bound)
+    }
+
+    /// Returns an [`InputDisplay`] for formatting as UTF-16 text.
+    pub fn display(&self) -> InputDisplay<'i> {
+        self.clone().into_hinted_bytes().display()
+    }
+
+    /// Decodes the input into a [`String`](alloc::string::String).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the input has an unpaired surrogate and
+    /// [`ExpectedLength`] if a UTF-16 code point was cut short.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_dangerous_string<E>(&self) -> Result<String, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let mut s = String::with_capacity(self.len() / 2);
+        let mut bytes = self.bytes;
+        while let Some(result) = utf16::decode_front(bytes, self.endian) {
+            match result {
+                Ok((c, len)) => {
+                    s.push(c);
+                    bytes = &bytes[len..];
+                }
+                Err(err) => return Err(self.decode_error(bytes, err, "convert input to string")),
+            }
+        }
+        Ok(s)
+    }
+
+    /// Create a reader with the expectation all of the input is read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the provided function does, or there is
+    /// trailing input.
+    pub fn read_all<F, T, E>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Utf16Reader<'i, E>) -> Result<T, E>,
+        E: WithContext<'i>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let mut r = Utf16Reader::new(self.clone());
+        let ok = r.context(OperationContext("read all"), f)?;
+        let remaining = r.take_remaining();
+        if remaining.is_empty() {
+            Ok(ok)
+        } else {
+            Err(E::from(ExpectedLength {
+                len: Length::Exactly(0),
+                span: remaining.bytes,
+                input: self.into_maybe_string(),
+                context: ExpectedContext {
+                    operation: "read all",
+                    expected: "no trailing input",
+                },
+            }))
+        }
+    }
+
+    /// Create a reader to read a part of the input and return the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provided function does.
+    pub fn read_partial<F, T, E>(self, f: F) -> Result<(T, Utf16<'i>), E>
+    where
+        F: FnOnce(&mut Utf16Reader<'i, E>) -> Result<T, E>,
+        E: WithContext<'i>,
+    {
+        let mut r = Utf16Reader::new(self);
+        let ok = r.context(OperationContext("read partial"), f)?;
+        Ok((ok, r.take_remaining()))
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    pub(crate) fn into_maybe_string(self) -> MaybeString<'i> {
+        MaybeString::Bytes(self.into_hinted_bytes())
+    }
+
+    /// Returns the underlying bytes hinted to be displayed as UTF-16 text.
+    pub(crate) fn into_hinted_bytes(self) -> Bytes<'i> {
+        let endian = self.endian;
+        self.into_bytes().with_utf16_hint(endian)
+    }
+
+    /// Splits the input at a byte index which must be on a code point
+    /// boundary.
+    pub(crate) fn split_at_byte(self, mid: usize) -> (Self, Self) {
+        let (head, tail) = self.bytes.split_at(mid);
+        (
+            Self::new(head, self.endian, self.bound.close_end()),
+            Self::new(tail, self.endian, self.bound),
+        )
+    }
+
+    /// Returns an error for a code point that failed to decode at the start
+    /// of `at`, a sub-slice of the input.
+    pub(crate) fn decode_error<E>(
+        &self,
+        at: &'i [u8],
+        err: InvalidUnit,
+        operation: &'static str,
+    ) -> E
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        match err {
+            InvalidUnit::Incomplete(len) => E::from(ExpectedLength {
+                len: Length::AtLeast(len),
+                span: at,
+                input: self.clone().into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "complete utf-16 code point",
+                },
+            }),
+            InvalidUnit::Unpaired => E::from(ExpectedValid {
+                span: &at[..2],
+                input: self.clone().into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "utf-16 code point",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            }),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Equality
+
+impl PartialEq for Utf16<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.endian == other.endian {
+            self.bytes == other.bytes
+        } else {
+            self.bytes.len() == other.bytes.len()
+                && self
+                    .bytes
+                    .chunks(2)
+                    .zip(other.bytes.chunks(2))
+                    .all(|(a, b)| a.iter().eq(b.iter().rev()))
+        }
+    }
+}
+
+impl PartialEq<str> for Utf16<'_> {
+    fn eq(&self, other: &str) -> bool {
+        utf16::encode_str(other, self.endian).eq(self.bytes.iter().copied())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Formatting
+
+impl fmt::Debug for Utf16<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16")
+            .field("bound", &self.bound)
+            .field("endian", &self.endian)
+            .field("value", &self.display())
+            .finish()
+    }
+}
+
+impl fmt::DisplayBase for Utf16<'_> {
+    fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.display().fmt(w)
+    }
+}
+
+impl fmt::Display for Utf16<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = self.display();
+        match f.precision() {
+            Some(width) => fmt::Display::fmt(&display.head_tail(width), f),
+            None => fmt::Display::fmt(&display, f),
+        }
+    }
+}
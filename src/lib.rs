@@ -15,16 +15,29 @@
 //!
 //! # Feature flags
 //!
-//! | Feature        | Default     | Description
-//! | -------------- | ----------- | ----------------------------------------------- |
-//! | `std`          | _Disabled_  | Enables `std::error::Error` support and `alloc` |
-//! | `alloc`        | **Enabled** | Enables allocations.                            |
-//! | `retry`        | **Enabled** | Enables retry support.                          |
-//! | `simd`         | **Enabled** | Enables all supported SIMD optimisations.       |
-//! | `unicode`      | **Enabled** | Enables improved unicode printing support.      |
-//! | `full-context` | **Enabled** | Enables full context backtraces.                |
-//! | `zc`           | _Disabled_  | Enables `zc` crate support.                     |
-//! | `regex`        | _Disabled_  | Enables `regex` pattern support.                |
+//! | Feature                 | Default     | Description
+//! | ----------------------- | ----------- | ----------------------------------------------- |
+//! | `std`                   | _Disabled_  | Enables `std::error::Error` support and `alloc` |
+//! | `alloc`                 | **Enabled** | Enables allocations.                            |
+//! | `retry`                 | **Enabled** | Enables retry support.                          |
+//! | `simd`                  | **Enabled** | Enables all supported SIMD optimisations.       |
+//! | `unicode`               | **Enabled** | Enables unicode printing, graphemes and XIDs.   |
+//! | `full-context`          | **Enabled** | Enables full context backtraces.                |
+//! | `zc`                    | _Disabled_  | Enables `zc` crate support.                     |
+//! | `regex`                 | _Disabled_  | Enables `regex` pattern support.                |
+//! | `aho-corasick`          | _Disabled_  | Enables multi-literal pattern support.          |
+//! | `bytemuck`              | _Disabled_  | Enables zero-copy reading of plain-old-data.    |
+//! | `futures-io`            | _Disabled_  | Enables async streams with `std` and `retry`.   |
+//! | `tokio-codec`           | _Disabled_  | Enables the `tokio-util` codec adapter.         |
+//! | `bytes`                 | _Disabled_  | Enables `bytes` crate input support.            |
+//! | `serde`                 | _Disabled_  | Enables serde deserialization with `std`.       |
+//! | `memmap`                | _Disabled_  | Enables memory-mapped file input with `std`.    |
+//! | `nom`                   | _Disabled_  | Enables running `nom` parsers on input.         |
+//! | `testing`               | _Disabled_  | Enables the parser invariant testing harness.   |
+//! | `arbitrary`             | _Disabled_  | Enables `arbitrary` support for `Bytes`.        |
+//! | `proptest`              | _Disabled_  | Enables `testing` with `proptest` support.      |
+//! | `unicode-normalization` | _Disabled_  | Enables NFC normalization checks.               |
+//! | `unicode-security`      | _Disabled_  | Enables UTS #39 confusable checks.              |
 
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -37,7 +50,7 @@
 )]
 #![deny(
     unused,
-    rustdoc,
+    rustdoc::all,
     rust_2018_idioms,
     clippy::all,
     clippy::correctness,
@@ -57,7 +70,7 @@
     // https://github.com/rust-lang/rust-clippy/issues/5822
     clippy::option_if_let_else,
     // https://github.com/rust-lang/rust/issues/72081
-    private_doc_tests,
+    rustdoc::private_doc_tests,
 )]
 
 #[cfg(feature = "alloc")]
@@ -70,8 +83,22 @@ mod input;
 mod reader;
 mod util;
 
+pub mod checksum;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod de;
 pub mod display;
+pub mod encoding;
 pub mod error;
+#[cfg(feature = "memmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap")))]
+pub mod mmap;
+#[cfg(all(feature = "std", feature = "retry"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "retry"))))]
+pub mod stream;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 
 pub use self::error::{Error, Expected, Fatal, FromExpected, WithContext};
 #[cfg(feature = "retry")]
@@ -80,8 +107,16 @@ pub use self::error::{Invalid, ToRetryRequirement};
 #[cfg(feature = "retry")]
 #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
 pub use self::input::Bound;
-pub use self::input::{input, Bytes, Input, MaybeString, Pattern, String};
-pub use self::reader::{BytesReader, Peek, Reader, StringReader};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::input::SharedBytes;
+pub use self::input::{
+    input, pattern, AllowedControls, Bytes, Chunks, Input, Lines, MaybeString, Pattern, Split,
+    SplitN, String, Utf16,
+};
+pub use self::reader::{
+    BytesReader, ChunksReader, Endian, Peek, Reader, StringReader, Utf16Reader,
+};
 
 // Re-exported types from core::fmt along with `DisplayBase` and `Write`.
 // This is used crate wide with the exception of crate::display.
@@ -1,8 +1,8 @@
 macro_rules! impl_read_num {
-    ($ty:ident, le: $read_le:ident, be: $read_be:ident) => {
-        impl_read_num!($ty, stringify!($ty), le: $read_le, be: $read_be);
+    ($ty:ident, le: $read_le:ident, be: $read_be:ident, endian: $read:ident) => {
+        impl_read_num!($ty, stringify!($ty), le: $read_le, be: $read_be, endian: $read);
     };
-    ($ty:ident, $ty_str:expr, le: $read_le:ident, be: $read_be:ident) => {
+    ($ty:ident, $ty_str:expr, le: $read_le:ident, be: $read_be:ident, endian: $read:ident) => {
         #[doc = "Read a little-endian encoded `"]
         #[doc = $ty_str]
         #[doc = "`."]
@@ -36,6 +36,125 @@ macro_rules! impl_read_num {
         {
             read_num!(self, E, $ty, concat!("big-endian ", $ty_str), from_be_bytes)
         }
+
+        #[doc = "Read a `"]
+        #[doc = $ty_str]
+        #[doc = "` encoded in the byte order of [`Reader::endian()`]."]
+        ///
+        /// See [`Reader::with_endian()`] for selecting the byte order.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        ///
+        /// [`Reader::endian()`]: crate::Reader::endian()
+        /// [`Reader::with_endian()`]: crate::Reader::with_endian()
+        #[inline]
+        pub fn $read(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            match self.endian {
+                Endian::Big => self.$read_be(),
+                Endian::Little => self.$read_le(),
+            }
+        }
+    };
+}
+
+macro_rules! impl_read_odd_num {
+    (
+        $name:ident: $ty:ident, $split_arr:ident,
+        le: $read_le:ident, be: $read_be:ident, endian: $read:ident
+    ) => {
+        impl_read_odd_num!(
+            $name: $ty, $split_arr, |v| v,
+            le: $read_le, be: $read_be, endian: $read
+        );
+    };
+    (
+        $name:ident: $ty:ident, $split_arr:ident, |$v:ident| $map:expr,
+        le: $read_le:ident, be: $read_be:ident, endian: $read:ident
+    ) => {
+        impl_read_odd_num!(
+            $name: $ty, stringify!($name), stringify!($ty), $split_arr, |$v| $map,
+            le: $read_le, be: $read_be, endian: $read
+        );
+    };
+    (
+        $name:ident: $ty:ident, $name_str:expr, $ty_str:expr, $split_arr:ident, |$v:ident| $map:expr,
+        le: $read_le:ident, be: $read_be:ident, endian: $read:ident
+    ) => {
+        #[doc = "Read a little-endian encoded `"]
+        #[doc = $name_str]
+        #[doc = "` into a `"]
+        #[doc = $ty_str]
+        #[doc = "`."]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        pub fn $read_le(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            self.try_advance(|input| {
+                let operation = concat!("read little-endian ", $name_str);
+                let (arr, next) = input.$split_arr(operation)?;
+                let mut buf = [0; core::mem::size_of::<$ty>()];
+                buf[..arr.len()].copy_from_slice(&arr);
+                let $v = <$ty>::from_le_bytes(buf);
+                Ok(($map, next))
+            })
+        }
+
+        #[doc = "Read a big-endian encoded `"]
+        #[doc = $name_str]
+        #[doc = "` into a `"]
+        #[doc = $ty_str]
+        #[doc = "`."]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        pub fn $read_be(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            self.try_advance(|input| {
+                let operation = concat!("read big-endian ", $name_str);
+                let (arr, next) = input.$split_arr(operation)?;
+                let mut buf = [0; core::mem::size_of::<$ty>()];
+                buf[core::mem::size_of::<$ty>() - arr.len()..].copy_from_slice(&arr);
+                let $v = <$ty>::from_be_bytes(buf);
+                Ok(($map, next))
+            })
+        }
+
+        #[doc = "Read a `"]
+        #[doc = $name_str]
+        #[doc = "` encoded in the byte order of [`Reader::endian()`] into a `"]
+        #[doc = $ty_str]
+        #[doc = "`."]
+        ///
+        /// See [`Reader::with_endian()`] for selecting the byte order.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        ///
+        /// [`Reader::endian()`]: crate::Reader::endian()
+        /// [`Reader::with_endian()`]: crate::Reader::with_endian()
+        #[inline]
+        pub fn $read(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            match self.endian {
+                Endian::Big => self.$read_be(),
+                Endian::Little => self.$read_le(),
+            }
+        }
     };
 }
 
@@ -50,9 +169,6 @@ macro_rules! read_num {
 }
 
 macro_rules! split_arr {
-    ($input:expr, u8, $expected:expr) => {
-        $input.split_arr_1($expected)
-    };
     ($input:expr, u16, $expected:expr) => {
         $input.split_arr_2($expected)
     };
@@ -65,9 +181,6 @@ macro_rules! split_arr {
     ($input:expr, u128, $expected:expr) => {
         $input.split_arr_16($expected)
     };
-    ($input:expr, i8, $expected:expr) => {
-        $input.split_arr_1($expected)
-    };
     ($input:expr, i16, $expected:expr) => {
         $input.split_arr_2($expected)
     };
@@ -0,0 +1,389 @@
+//! Memory-mapped file input.
+//!
+//! Large files can be parsed without first reading them into memory.
+//!
+//! - [`MappedFile`] maps a whole file and hands out its contents as bound
+//!   [`Bytes`], borrowed for the lifetime of the mapping.
+//! - [`MappedDecoder`] maps a file a window at a time and decodes messages
+//!   from each window, using the retry mechanism to grow the window when a
+//!   message straddles its end, if the `retry` feature is enabled.
+//!
+//! # Safety
+//!
+//! The contents of a mapped file can change underneath the mapping if the
+//! file is modified by this or another process, which would invalidate any
+//! input borrowed from it. Mapping a file is therefore `unsafe` and it is up
+//! to the caller to ensure the file is not modified while mapped.
+
+#[cfg(feature = "retry")]
+use core::convert::TryFrom;
+#[cfg(feature = "retry")]
+use core::iter::FusedIterator;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+#[cfg(feature = "retry")]
+use memmap2::MmapOptions;
+
+use crate::fmt;
+use crate::input::{Bound, Bytes, IntoInput};
+#[cfg(feature = "retry")]
+use crate::{error::ToRetryRequirement, stream::StreamError, BytesReader, Expected, Input};
+
+/// A file mapped read-only into memory.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+/// use dangerous::mmap::MappedFile;
+///
+/// # let path = std::env::temp_dir().join("dangerous-mapped-file-doctest");
+/// # std::fs::write(&path, b"hello world").unwrap();
+/// let file = unsafe { MappedFile::open(&path) }.unwrap();
+///
+/// let result: Result<_, Invalid> = file.input().read_all(|r| {
+///     let hello = r.take_until_consume(b' ')?;
+///     Ok((hello, r.take_remaining()))
+/// });
+///
+/// assert_eq!(result.unwrap(), (dangerous::input(b"hello"), dangerous::input(b"world")));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "memmap")))]
+pub struct MappedFile {
+    map: Mmap,
+}
+
+impl MappedFile {
+    /// Opens the file at `path` and maps it into memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is mapped, see the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be opened or mapped.
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::map(&File::open(path)?)
+    }
+
+    /// Maps the file into memory.
+    ///
+    /// The file only needs to be open for reading, and may be closed once
+    /// mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is mapped, see the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be mapped.
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        Mmap::map(file).map(|map| Self { map })
+    }
+
+    /// Returns the length of the mapped file.
+    #[must_use]
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the mapped file is empty.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the contents of the mapped file.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_dangerous(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// Returns the contents of the mapped file as [`Bytes`].
+    ///
+    /// **The input is bound**, as with [`Input::into_bound()`]. The whole file
+    /// is mapped so there is no more input to come, and running out of input
+    /// is a fatal error rather than one that can be retried.
+    ///
+    /// [`Input::into_bound()`]: crate::Input::into_bound()
+    #[inline(always)]
+    pub fn input(&self) -> Bytes<'_> {
+        Bytes::new(self.as_dangerous(), Bound::force_close())
+    }
+}
+
+impl fmt::Debug for MappedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedFile")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'i> IntoInput<'i> for &'i MappedFile {
+    type Input = Bytes<'i>;
+
+    #[inline(always)]
+    fn into_input(self) -> Self::Input {
+        self.input()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Decoder
+
+/// Decodes messages from a file mapped into memory a window at a time.
+///
+/// Each message is decoded from the mapped window starting at the first byte
+/// not yet consumed. The window is passed to the parser as unbound [`Bytes`]
+/// unless it reaches the end of the file. If a message straddles the end of
+/// the window and the parser fails with an error that can be retried, the
+/// file is remapped from the start of the message with a window large enough
+/// for the [`RetryRequirement`] and the parser is retried.
+///
+/// As with [`StreamDecoder`], parsers are provided the [`Expected`] error and
+/// decoded messages must own their values, as the window they were decoded
+/// from may be unmapped.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{BytesReader, Expected};
+/// use dangerous::mmap::MappedDecoder;
+///
+/// fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+///     let len = r.read_u8()?;
+///     Ok(r.take(len as usize)?.as_dangerous().to_vec())
+/// }
+///
+/// # let path = std::env::temp_dir().join("dangerous-mapped-decoder-doctest");
+/// # std::fs::write(&path, [2, b'h', b'i', 3, b'y', b'o', b'u']).unwrap();
+/// let mut decoder = unsafe { MappedDecoder::open(&path, 4) }.unwrap();
+///
+/// let messages: Vec<_> = decoder.messages(decode_message).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(messages, [&b"hi"[..], b"you"]);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// [`RetryRequirement`]: crate::error::RetryRequirement
+/// [`StreamDecoder`]: crate::stream::StreamDecoder
+#[cfg(feature = "retry")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "memmap", feature = "retry"))))]
+pub struct MappedDecoder {
+    file: File,
+    file_len: u64,
+    window_len: usize,
+    /// The file offset of the first byte not yet consumed.
+    offset: u64,
+    /// The current window and the file offset it was mapped from.
+    window: Option<(Mmap, u64)>,
+}
+
+#[cfg(feature = "retry")]
+impl MappedDecoder {
+    /// Opens the file at `path` to be mapped in windows of at least
+    /// `window_len` bytes.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is being decoded, see the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file could not be opened.
+    pub unsafe fn open(path: impl AsRef<Path>, window_len: usize) -> io::Result<Self> {
+        Self::new(File::open(path)?, window_len)
+    }
+
+    /// Creates a new `MappedDecoder` mapping `file` in windows of at least
+    /// `window_len` bytes.
+    ///
+    /// The length of the file is fixed on creation.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is being decoded, see the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of the file could not be read.
+    pub unsafe fn new(file: File, window_len: usize) -> io::Result<Self> {
+        Ok(Self {
+            file_len: file.metadata()?.len(),
+            file,
+            window_len: window_len.max(1),
+            offset: 0,
+            window: None,
+        })
+    }
+
+    /// Returns the file offset of the first byte not yet consumed by a
+    /// message.
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Consumes `self` into the underlying file.
+    #[must_use]
+    pub fn into_inner(self) -> File {
+        self.file
+    }
+
+    /// Decodes the next message with `f`, remapping the file until the window
+    /// holds enough input.
+    ///
+    /// Returns `Ok(None)` if the end of the file was reached between
+    /// messages.
+    ///
+    /// # Errors
+    ///
+    /// - [`StreamError::Io`] if mapping the file failed.
+    /// - [`StreamError::Invalid`] if the message was invalid, or was cut short
+    ///   by the end of the file. Calling `decode` again will return the same
+    ///   error as the invalid input is not consumed.
+    #[allow(clippy::result_large_err)]
+    pub fn decode<F, T>(&mut self, mut f: F) -> Result<Option<T>, StreamError>
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        let (offset, file_len) = (self.offset, self.file_len);
+        let mut needed = 1;
+        loop {
+            if offset == file_len {
+                return Ok(None);
+            }
+            let window = self.window(needed)?;
+            let len = window.len();
+            let at_end = offset + len as u64 == file_len;
+            let bound = if at_end {
+                Bound::force_close()
+            } else {
+                Bound::Start
+            };
+            match Bytes::new(window, bound).read_partial(|r| f(r)) {
+                Ok((message, remaining)) => {
+                    let consumed = len - remaining.len();
+                    self.offset = offset + consumed as u64;
+                    return Ok(Some(message));
+                }
+                Err(err) => match err.to_retry_requirement() {
+                    Some(requirement) if !at_end => needed = len + requirement.continue_after(),
+                    _ => return Err(StreamError::Invalid(err.into_owned())),
+                },
+            }
+        }
+    }
+
+    /// Returns an iterator decoding messages with `f` until the end of the
+    /// file.
+    ///
+    /// The iterator ends after the first error, or after a message that
+    /// consumed no input as decoding would otherwise return the same message
+    /// forever.
+    pub fn messages<F, T>(&mut self, f: F) -> MappedMessages<'_, F>
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        MappedMessages {
+            decoder: self,
+            f,
+            done: false,
+        }
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    /// Returns the mapped bytes from the offset, remapping the file if fewer
+    /// than `needed` bytes are mapped before the end of the file.
+    fn window(&mut self, needed: usize) -> io::Result<&[u8]> {
+        let remaining = usize::try_from(self.file_len - self.offset).unwrap_or(usize::MAX);
+        let (mut start, mapped) = match &self.window {
+            Some((map, map_offset)) => {
+                // The offset only moves forward within the current window,
+                // which is no longer than `usize::MAX`.
+                #[allow(clippy::cast_possible_truncation)]
+                let start = (self.offset - map_offset) as usize;
+                (start, map.len() - start)
+            }
+            None => (0, 0),
+        };
+        if self.window.is_none() || mapped < needed.min(remaining) {
+            let len = needed.max(self.window_len).max(mapped + 1).min(remaining);
+            // SAFETY: the caller of the constructor ensures the file is not
+            // modified while it is being decoded.
+            let map = unsafe {
+                MmapOptions::new()
+                    .offset(self.offset)
+                    .len(len)
+                    .map(&self.file)?
+            };
+            self.window = Some((map, self.offset));
+            start = 0;
+        }
+        Ok(self.window.as_ref().map_or(&[], |(map, _)| &map[start..]))
+    }
+}
+
+#[cfg(feature = "retry")]
+impl fmt::Debug for MappedDecoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedDecoder")
+            .field("file_len", &self.file_len)
+            .field("window_len", &self.window_len)
+            .field("offset", &self.offset)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over messages decoded from a [`MappedDecoder`].
+///
+/// Created with [`MappedDecoder::messages()`].
+#[cfg(feature = "retry")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "memmap", feature = "retry"))))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MappedMessages<'a, F> {
+    decoder: &'a mut MappedDecoder,
+    f: F,
+    done: bool,
+}
+
+#[cfg(feature = "retry")]
+impl<F, T> Iterator for MappedMessages<'_, F>
+where
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    type Item = Result<T, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let consumed = self.decoder.offset();
+        let result = self.decoder.decode(&mut self.f).transpose();
+        self.done = !matches!(result, Some(Ok(_))) || self.decoder.offset() == consumed;
+        result
+    }
+}
+
+#[cfg(feature = "retry")]
+impl<F, T> FusedIterator for MappedMessages<'_, F> where
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>
+{
+}
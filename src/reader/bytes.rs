@@ -1,7 +1,8 @@
-use crate::error::{ExpectedLength, ExpectedValid, WithContext};
+use crate::checksum::Checksum;
+use crate::error::{ExpectedLength, ExpectedValid, ExpectedValue, OperationContext, WithContext};
 use crate::input::{PrivateExt, String};
 
-use super::BytesReader;
+use super::{BytesReader, Endian};
 
 impl<'i, E> BytesReader<'i, E> {
     /// Skip a length of string input while a predicate check remains true.
@@ -124,14 +125,331 @@ impl<'i, E> BytesReader<'i, E> {
             .map(|v| i8::from_ne_bytes([v]))
     }
 
-    impl_read_num!(u16, le: read_u16_le, be: read_u16_be);
-    impl_read_num!(i16, le: read_i16_le, be: read_i16_be);
-    impl_read_num!(u32, le: read_u32_le, be: read_u32_be);
-    impl_read_num!(i32, le: read_i32_le, be: read_i32_be);
-    impl_read_num!(u64, le: read_u64_le, be: read_u64_be);
-    impl_read_num!(i64, le: read_i64_le, be: read_i64_be);
-    impl_read_num!(u128, le: read_u128_le, be: read_u128_be);
-    impl_read_num!(i128, le: read_i128_le, be: read_i128_be);
-    impl_read_num!(f32, le: read_f32_le, be: read_f32_be);
-    impl_read_num!(f64, le: read_f64_le, be: read_f64_be);
+    impl_read_num!(u16, le: read_u16_le, be: read_u16_be, endian: read_u16);
+    impl_read_num!(i16, le: read_i16_le, be: read_i16_be, endian: read_i16);
+    impl_read_odd_num!(u24: u32, split_arr_3, le: read_u24_le, be: read_u24_be, endian: read_u24);
+    impl_read_odd_num!(
+        i24: i32, split_arr_3, |v| (v << 8) >> 8,
+        le: read_i24_le, be: read_i24_be, endian: read_i24
+    );
+    impl_read_num!(u32, le: read_u32_le, be: read_u32_be, endian: read_u32);
+    impl_read_num!(i32, le: read_i32_le, be: read_i32_be, endian: read_i32);
+    impl_read_odd_num!(u48: u64, split_arr_6, le: read_u48_le, be: read_u48_be, endian: read_u48);
+    impl_read_num!(u64, le: read_u64_le, be: read_u64_be, endian: read_u64);
+    impl_read_num!(i64, le: read_i64_le, be: read_i64_be, endian: read_i64);
+    impl_read_num!(u128, le: read_u128_le, be: read_u128_be, endian: read_u128);
+    impl_read_num!(i128, le: read_i128_le, be: read_i128_be, endian: read_i128);
+    impl_read_num!(f32, le: read_f32_le, be: read_f32_be, endian: read_f32);
+    impl_read_num!(f64, le: read_f64_le, be: read_f64_be, endian: read_f64);
+
+    /// Read a little-endian encoded unsigned integer `len` bytes long.
+    ///
+    /// Integers longer than 8 bytes are accepted if the bytes beyond the width
+    /// of a `u64` are zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// read and [`ExpectedValid`] if the integer does not fit in a `u64`.
+    pub fn read_uint_le(&mut self, len: usize) -> Result<u64, E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_uint(len, Endian::Little, "read little-endian uint"))
+    }
+
+    /// Read a big-endian encoded unsigned integer `len` bytes long.
+    ///
+    /// Integers longer than 8 bytes are accepted if the bytes beyond the width
+    /// of a `u64` are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[0x01, 0x02, 0x03]).read_all(|r| {
+    ///     r.read_uint_be(3)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 0x01_02_03);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// read and [`ExpectedValid`] if the integer does not fit in a `u64`.
+    pub fn read_uint_be(&mut self, len: usize) -> Result<u64, E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_uint(len, Endian::Big, "read big-endian uint"))
+    }
+
+    /// Read an unsigned integer `len` bytes long encoded in the byte order of
+    /// [`Reader::endian()`].
+    ///
+    /// See [`Reader::with_endian()`] for selecting the byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// read and [`ExpectedValid`] if the integer does not fit in a `u64`.
+    ///
+    /// [`Reader::endian()`]: crate::Reader::endian()
+    /// [`Reader::with_endian()`]: crate::Reader::with_endian()
+    #[inline]
+    pub fn read_uint(&mut self, len: usize) -> Result<u64, E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        match self.endian {
+            Endian::Big => self.read_uint_be(len),
+            Endian::Little => self.read_uint_le(len),
+        }
+    }
+
+    /// Read a reference to a plain-old-data value without copying.
+    ///
+    /// # Alignment
+    ///
+    /// The value is cast directly from the input, so the input **must** be
+    /// aligned for `T` at the position read. There is no copying fallback:
+    /// misaligned input is an error, and for a `T` with an alignment above
+    /// one whether input is aligned depends on where the buffer was
+    /// allocated, not on its contents. Only use this method on input known to
+    /// be aligned, such as a buffer allocated for `T` or a memory-mapped file
+    /// with a suitably aligned layout. Use [`BytesReader::read_pod()`] to read
+    /// a copy of the value otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[0xff, 0x01]).read_all(|r| {
+    ///     r.read_ref::<[u8; 2]>()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), &[0xff, 0x01]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// read and [`ExpectedValid`] if the input is not aligned for `T`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn read_ref<T>(&mut self) -> Result<&'i T, E>
+    where
+        T: bytemuck::Pod,
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| {
+            input
+                .split_pod_slice(1, "read ref")
+                .map(|(values, next)| (&values[0], next))
+        })
+    }
+
+    /// Read a slice of `count` plain-old-data values without copying.
+    ///
+    /// # Alignment
+    ///
+    /// The values are cast directly from the input, so the input **must** be
+    /// aligned for `T` at the position read. As with
+    /// [`BytesReader::read_ref()`] there is no copying fallback, and
+    /// misaligned input is an error even if the values are valid. Use
+    /// [`BytesReader::read_pod()`] for each value to read copies if the
+    /// alignment of the input is not guaranteed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// read and [`ExpectedValid`] if the input is not aligned for `T`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn read_slice<T>(&mut self, count: usize) -> Result<&'i [T], E>
+    where
+        T: bytemuck::Pod,
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_pod_slice(count, "read slice"))
+    }
+
+    /// Read a copy of a plain-old-data value.
+    ///
+    /// Unlike [`BytesReader::read_ref()`] and [`BytesReader::read_slice()`],
+    /// the input does not need to be aligned for `T`, so this is the fallback
+    /// for input of unknown alignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is not sufficient input left to read.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn read_pod<T>(&mut self) -> Result<T, E>
+    where
+        T: bytemuck::Pod,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| {
+            input
+                .split_at(core::mem::size_of::<T>(), "read pod")
+                .map(|(head, next)| (bytemuck::pod_read_unaligned(head.as_dangerous()), next))
+        })
+    }
+
+    /// Read with a `nom` parser, advancing by what it consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    /// use nom::bytes::complete::tag;
+    ///
+    /// let input = dangerous::input(b"GET /");
+    /// let result: Result<_, Invalid> = input.read_all(|r| {
+    ///     r.read_nom("method", tag(b"GET"))?;
+    ///     Ok(r.take_remaining())
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b" /"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the parser fails, spanning the input the
+    /// parser failed at. If the parser needs more input and this input is not
+    /// bound, the error carries the number of bytes needed as its
+    /// [`RetryRequirement`].
+    ///
+    /// [`RetryRequirement`]: crate::error::RetryRequirement
+    #[cfg(feature = "nom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nom")))]
+    pub fn read_nom<O, P>(&mut self, expected: &'static str, parser: P) -> Result<O, E>
+    where
+        P: nom::Parser<&'i [u8], O, nom::error::Error<&'i [u8]>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_nom(parser, expected, "read nom"))
+    }
+
+    /// Read with a `nom` parser taking [`Bytes`] as input, advancing by what
+    /// it consumed.
+    ///
+    /// Unlike [`BytesReader::read_nom()`], streaming combinators only ask for
+    /// more input if the input is not bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the parser fails. See
+    /// [`BytesReader::read_nom()`].
+    ///
+    /// [`Bytes`]: crate::Bytes
+    #[cfg(feature = "nom")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "nom")))]
+    pub fn read_nom_bytes<O, P>(&mut self, expected: &'static str, parser: P) -> Result<O, E>
+    where
+        P: nom::Parser<crate::Bytes<'i>, O, nom::error::Error<crate::Bytes<'i>>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_nom(parser, expected, "read nom bytes"))
+    }
+
+    /// Read with a [`Checksum`] calculated over the input consumed by the
+    /// provided function, verifying it against the checksum that follows.
+    ///
+    /// The trailing checksum is read in the byte order of
+    /// [`Reader::endian()`] with the width of the checksum algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Endian, Input, Invalid};
+    /// use dangerous::checksum::Crc32;
+    ///
+    /// let input = dangerous::input(b"123456789\xcb\xf4\x39\x26");
+    /// let result: Result<_, Invalid> = input.read_all(|r| {
+    ///     r.with_endian(Endian::Big, |r| {
+    ///         r.verify_checksum(Crc32::ieee(), |r| Ok(r.take(9)?))
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b"123456789"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error the provided function does, [`ExpectedLength`] if
+    /// there is not sufficient input left to read the checksum and
+    /// [`ExpectedValue`] if the checksum does not match.
+    ///
+    /// [`Reader::endian()`]: crate::Reader::endian()
+    pub fn verify_checksum<C, F, T>(&mut self, mut checksum: C, f: F) -> Result<T, E>
+    where
+        C: Checksum,
+        E: WithContext<'i>,
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValue<'i>>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        self.context(OperationContext("verify checksum"), |r| {
+            let checkpoint = r.input.clone();
+            let ok = f(r)?;
+            let consumed = &checkpoint.as_dangerous()[..checkpoint.len() - r.input.len()];
+            checksum.update(consumed);
+            let endian = r.endian;
+            r.try_advance(|input| input.split_checksum(&checksum, endian, "read checksum"))
+                .map(|()| ok)
+        })
+    }
+
+    /// Consume an expected secret without exiting early on the first byte
+    /// that differs.
+    ///
+    /// Use this over [`Reader::consume()`] when the expected value is a secret,
+    /// such as a MAC tag or token, to avoid exposing which byte first differed
+    /// through timing. This is best effort and not a guarantee of constant
+    /// time execution. The length of `secret` is always compared in variable
+    /// time.
+    ///
+    /// Doesn't effect the internal state of the `Reader` if the input couldn't
+    /// be consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let tag = [0xde, 0xad, 0xbe, 0xef];
+    /// let result: Result<_, Invalid> = dangerous::input(&[0xde, 0xad, 0xbe, 0xef, 0x01])
+    ///     .read_all(|r| {
+    ///         r.consume_ct(&tag)?;
+    ///         r.read_u8()
+    ///     });
+    ///
+    /// assert_eq!(result.unwrap(), 0x01);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is not sufficient input left to
+    /// compare and [`ExpectedValid`] spanning the whole length of `secret` if
+    /// the input does not match.
+    ///
+    /// [`Reader::consume()`]: crate::Reader::consume()
+    pub fn consume_ct(&mut self, secret: &[u8]) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_prefix_ct(secret, "consume secret"))
+            .map(drop)
+    }
 }
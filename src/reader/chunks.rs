@@ -0,0 +1,327 @@
+use core::marker::PhantomData;
+
+use crate::error::{Context, ExpectedContext, ExpectedLength, ExpectedValue, Value, WithContext};
+use crate::fmt;
+use crate::input::pattern::Pattern;
+use crate::input::{Bound, Bytes, Chunks};
+
+/// A reader over [`Chunks`], reading across chunk boundaries.
+///
+/// Input taken from the reader is returned as [`Chunks`] without copying.
+///
+/// # Errors
+///
+/// Functions on `ChunksReader` return errors relative to the chunk they
+/// occurred within, with a [`ChunkContext`] added carrying where that chunk
+/// starts in the logical stream. Errors at the end of the input can be
+/// retried if the [`Chunks`] are not bound.
+///
+/// [`ChunkContext`]: crate::error::ChunkContext
+pub struct ChunksReader<'i, E> {
+    input: Chunks<'i>,
+    types: PhantomData<E>,
+}
+
+macro_rules! impl_read_chunks_num {
+    ($ty:ident, le: $read_le:ident, be: $read_be:ident) => {
+        #[doc = "Read a little-endian encoded `"]
+        #[doc = stringify!($ty)]
+        #[doc = "`."]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        pub fn $read_le(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>> + WithContext<'i>,
+        {
+            let mut buf = [0; core::mem::size_of::<$ty>()];
+            self.read_into(&mut buf, concat!("read little-endian ", stringify!($ty)))?;
+            Ok(<$ty>::from_le_bytes(buf))
+        }
+
+        #[doc = "Read a big-endian encoded `"]
+        #[doc = stringify!($ty)]
+        #[doc = "`."]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if there is not sufficient input left to read.
+        pub fn $read_be(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>> + WithContext<'i>,
+        {
+            let mut buf = [0; core::mem::size_of::<$ty>()];
+            self.read_into(&mut buf, concat!("read big-endian ", stringify!($ty)))?;
+            Ok(<$ty>::from_be_bytes(buf))
+        }
+    };
+}
+
+impl<'i, E> ChunksReader<'i, E> {
+    /// Create a `ChunksReader` given [`Chunks`].
+    pub(crate) fn new(input: Chunks<'i>) -> Self {
+        Self {
+            input,
+            types: PhantomData,
+        }
+    }
+
+    /// Returns the offset of the reader in the logical stream.
+    #[must_use]
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.input.offset()
+    }
+
+    /// Returns `true` if the reader has no more input to consume.
+    #[must_use]
+    #[inline(always)]
+    pub fn at_end(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Read all of the remaining input.
+    pub fn take_remaining(&mut self) -> Chunks<'i> {
+        let (head, tail) = self.input.split_at(self.input.len());
+        self.input = tail;
+        head
+    }
+
+    /// Mutably use the `ChunksReader` with a given context.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by the provided function with the specified
+    /// context attached.
+    pub fn context<F, T>(&mut self, context: impl Context, f: F) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let part = self.input.parts().next();
+        let part = part.unwrap_or_else(|| self.input.end_part());
+        f(self).map_err(|err| err.with_context(part.chunk_input(), context))
+    }
+
+    /// Skip `len` number of bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length requirement to skip could not be met.
+    pub fn skip(&mut self, len: usize) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        self.take_with(len, "skip").map(drop)
+    }
+
+    /// Read a length of input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length requirement to read could not be met.
+    pub fn take(&mut self, len: usize) -> Result<Chunks<'i>, E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        self.take_with(len, "take")
+    }
+
+    /// Read a length of input until the byte matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte could not be found.
+    pub fn take_until(&mut self, byte: u8) -> Result<Chunks<'i>, E>
+    where
+        E: From<ExpectedValue<'i>> + WithContext<'i>,
+    {
+        self.take_until_with(byte, 0, "take until")
+    }
+
+    /// Read a length of input until the byte matches and consumes the byte.
+    ///
+    /// Returns the input leading up to the byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte could not be found.
+    pub fn take_until_consume(&mut self, byte: u8) -> Result<Chunks<'i>, E>
+    where
+        E: From<ExpectedValue<'i>> + WithContext<'i>,
+    {
+        self.take_until_with(byte, 1, "take until consume")
+    }
+
+    /// Read a length of input until the byte optionally matches.
+    ///
+    /// Returns the input leading up to the byte if found, or the remaining
+    /// input if not.
+    pub fn take_until_opt(&mut self, byte: u8) -> Chunks<'i> {
+        match self.find(byte) {
+            Some(index) => {
+                let (head, tail) = self.input.split_at(index);
+                self.input = tail;
+                head
+            }
+            None => self.take_remaining(),
+        }
+    }
+
+    /// Consume expected bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes could not be consumed from the input.
+    /// The error is at the first byte that did not match, or at the end of
+    /// the input if it ran out part way through the expected bytes.
+    pub fn consume(&mut self, bytes: &[u8]) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>> + From<ExpectedValue<'i>> + WithContext<'i>,
+    {
+        let mut remaining = bytes;
+        for part in self.input.parts() {
+            if remaining.is_empty() {
+                break;
+            }
+            let actual = part.bytes();
+            let actual = &actual[..actual.len().min(remaining.len())];
+            // The expected bytes don't live as long as the input, so only the
+            // byte that did not match is kept for the error.
+            if let Some(i) = actual.iter().zip(remaining).position(|(a, b)| a != b) {
+                return Err(part.expected_value(
+                    &actual[i..=i],
+                    Value::from(remaining[i]),
+                    ExpectedContext {
+                        operation: "consume",
+                        expected: "exact value",
+                    },
+                ));
+            }
+            remaining = &remaining[actual.len()..];
+        }
+        if remaining.is_empty() {
+            self.input = self.input.split_at(bytes.len()).1;
+            Ok(())
+        } else {
+            Err(self.input.expected_length(bytes.len(), "consume"))
+        }
+    }
+
+    /// Peek the next byte in the input without consuming it.
+    ///
+    /// Returns `None` if the reader is at the end of the input.
+    #[must_use]
+    pub fn peek_u8_opt(&self) -> Option<u8> {
+        self.input.parts().next().map(|part| part.bytes()[0])
+    }
+
+    /// Peek the next byte in the input without consuming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader is at the end of the input.
+    pub fn peek_u8(&self) -> Result<u8, E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        self.peek_u8_opt()
+            .ok_or_else(|| self.input.expected_length(1, "peek u8"))
+    }
+
+    /// Read a byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no more input.
+    pub fn read_u8(&mut self) -> Result<u8, E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        let mut buf = [0; 1];
+        self.read_into(&mut buf, "read u8")?;
+        Ok(buf[0])
+    }
+
+    impl_read_chunks_num!(u16, le: read_u16_le, be: read_u16_be);
+    impl_read_chunks_num!(i16, le: read_i16_le, be: read_i16_be);
+    impl_read_chunks_num!(u32, le: read_u32_le, be: read_u32_be);
+    impl_read_chunks_num!(i32, le: read_i32_le, be: read_i32_be);
+    impl_read_chunks_num!(u64, le: read_u64_le, be: read_u64_be);
+    impl_read_chunks_num!(i64, le: read_i64_le, be: read_i64_be);
+    impl_read_chunks_num!(u128, le: read_u128_le, be: read_u128_be);
+    impl_read_chunks_num!(i128, le: read_i128_le, be: read_i128_be);
+    impl_read_chunks_num!(f32, le: read_f32_le, be: read_f32_be);
+    impl_read_chunks_num!(f64, le: read_f64_le, be: read_f64_be);
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    fn take_with(&mut self, len: usize, operation: &'static str) -> Result<Chunks<'i>, E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        if len > self.input.len() {
+            return Err(self.input.expected_length(len, operation));
+        }
+        let (head, tail) = self.input.split_at(len);
+        self.input = tail;
+        Ok(head)
+    }
+
+    fn take_until_with(
+        &mut self,
+        byte: u8,
+        consume: usize,
+        operation: &'static str,
+    ) -> Result<Chunks<'i>, E>
+    where
+        E: From<ExpectedValue<'i>> + WithContext<'i>,
+    {
+        if let Some(index) = self.find(byte) {
+            let (head, tail) = self.input.split_at(index);
+            self.input = tail.split_at(consume).1;
+            return Ok(head);
+        }
+        let part = self.input.end_part();
+        Err(part.expected_value(
+            part.bytes(),
+            Value::from(byte),
+            ExpectedContext {
+                operation,
+                expected: "pattern match",
+            },
+        ))
+    }
+
+    /// Returns the index of the first occurrence of `byte` in the input.
+    fn find(&self, byte: u8) -> Option<usize> {
+        let mut index = 0;
+        for bytes in self.input.slices() {
+            if let Some((i, _)) = byte.find_match(&Bytes::new(bytes, Bound::Both)) {
+                return Some(index + i);
+            }
+            index += bytes.len();
+        }
+        None
+    }
+
+    /// Reads enough input to fill `buf`.
+    fn read_into(&mut self, buf: &mut [u8], operation: &'static str) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>> + WithContext<'i>,
+    {
+        let head = self.take_with(buf.len(), operation)?;
+        head.copy_to(buf);
+        Ok(())
+    }
+}
+
+impl<E> fmt::Debug for ChunksReader<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunksReader")
+            .field("input", &self.input)
+            .finish()
+    }
+}
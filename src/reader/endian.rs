@@ -0,0 +1,103 @@
+use core::any::Any;
+
+use crate::error::Context;
+use crate::fmt;
+
+/// Byte order used by the endian-neutral number reading functions of a
+/// [`BytesReader`](crate::BytesReader).
+///
+/// The byte order can be selected at runtime with [`Reader::with_endian()`],
+/// which is useful for formats such as TIFF, ELF and pcap that declare the
+/// byte order of the data that follows in their header.
+///
+/// [`Reader::with_endian()`]: crate::Reader::with_endian()
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum Endian {
+    /// Most significant byte first (network byte order).
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl Endian {
+    /// Returns the byte order of the target platform.
+    #[must_use]
+    #[inline(always)]
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        }
+    }
+
+    /// Returns `true` if the byte order is [`Endian::Big`].
+    #[must_use]
+    #[inline(always)]
+    pub fn is_big(self) -> bool {
+        self == Endian::Big
+    }
+
+    /// Returns `true` if the byte order is [`Endian::Little`].
+    #[must_use]
+    #[inline(always)]
+    pub fn is_little(self) -> bool {
+        self == Endian::Little
+    }
+}
+
+impl Default for Endian {
+    /// Returns [`Endian::Big`] (network byte order).
+    fn default() -> Self {
+        Endian::Big
+    }
+}
+
+impl fmt::Debug for Endian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Big => "Big",
+            Self::Little => "Little",
+        };
+        f.write_str(s)
+    }
+}
+
+impl fmt::DisplayBase for Endian {
+    fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        match self {
+            Self::Big => w.write_str("big-endian"),
+            Self::Little => w.write_str("little-endian"),
+        }
+    }
+}
+
+impl fmt::Display for Endian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::DisplayBase::fmt(self, f)
+    }
+}
+
+impl Context for Endian {
+    fn operation(&self) -> &'static str {
+        match self {
+            Self::Big => "read big-endian",
+            Self::Little => "read little-endian",
+        }
+    }
+
+    fn has_expected(&self) -> bool {
+        false
+    }
+
+    fn expected(&self, _: &mut dyn fmt::Write) -> fmt::Result {
+        Err(fmt::Error)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(feature = "zc")]
+unsafe impl zc::NoInteriorMut for Endian {}
@@ -1,3 +1,5 @@
+#[cfg(feature = "aho-corasick")]
+use crate::input::pattern::AnyLiteral;
 use crate::input::{Input, Pattern, Prefix, PrivateExt};
 
 #[cfg(feature = "retry")]
@@ -7,7 +9,7 @@ use crate::error::{
     WithContext,
 };
 
-use super::{Peek, Reader};
+use super::{Endian, Peek, Reader};
 
 impl<'i, E, I> Reader<'i, E, I>
 where
@@ -56,12 +58,63 @@ where
         with_context(self.input.clone(), context, || f(self))
     }
 
+    /// Returns the [`Endian`] byte order the `Reader` currently reads with.
+    #[must_use]
+    #[inline(always)]
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Mutably use the `Reader` with a given [`Endian`] byte order.
+    ///
+    /// The byte order applies to the endian-neutral number reading functions
+    /// (e.g. [`BytesReader::read_u32()`]) called within the scope and is
+    /// restored once the provided function returns. Any error returned has the
+    /// byte order attached as a context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Endian, Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"II\x2a\x00").read_all(|r| {
+    ///     let endian = if r.consume_opt(b"II") {
+    ///         Endian::Little
+    ///     } else {
+    ///         r.consume(b"MM")?;
+    ///         Endian::Big
+    ///     };
+    ///     r.with_endian(endian, |r| r.read_u16())
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 42);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by the provided function with the byte order
+    /// attached as a context.
+    ///
+    /// [`BytesReader::read_u32()`]: crate::BytesReader::read_u32()
+    pub fn with_endian<F, T>(&mut self, endian: Endian, f: F) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let outer = self.endian;
+        self.endian = endian;
+        let result = self.context(endian, f);
+        self.endian = outer;
+        result
+    }
+
     /// Read a length of input that was successfully consumed from a sub-parse.
     pub fn take_consumed<F>(&mut self, consumer: F) -> I
     where
         F: FnOnce(&mut Self),
     {
-        self.advance(|input| input.split_consumed(consumer))
+        let endian = self.endian;
+        self.advance(|input| input.split_consumed(consumer, endian))
     }
 
     /// Try read a length of input that was successfully consumed from a
@@ -90,7 +143,8 @@ where
         E: WithContext<'i>,
         F: FnOnce(&mut Self) -> Result<(), E>,
     {
-        self.try_advance(|input| input.try_split_consumed(consumer, "try take consumed"))
+        let endian = self.endian;
+        self.try_advance(|input| input.try_split_consumed(consumer, endian, "try take consumed"))
     }
 
     /// Read and verify a value without returning it.
@@ -103,6 +157,7 @@ where
         F: FnOnce(&mut Self) -> bool,
         E: From<ExpectedValid<'i>>,
     {
+        let endian = self.endian;
         self.try_advance(|input| {
             input.split_expect(
                 |r: &mut Self| {
@@ -112,6 +167,7 @@ where
                         None
                     }
                 },
+                endian,
                 expected,
                 "verify",
             )
@@ -129,6 +185,7 @@ where
         E: WithContext<'i>,
         E: From<ExpectedValid<'i>>,
     {
+        let endian = self.endian;
         self.try_advance(|input| {
             input.try_split_expect(
                 |r: &mut Self| match verifier(r) {
@@ -136,6 +193,7 @@ where
                     Ok(false) => Ok(None),
                     Err(err) => Err(err),
                 },
+                endian,
                 expected,
                 "try verify",
             )
@@ -152,7 +210,8 @@ where
         F: FnOnce(&mut Self) -> Option<T>,
         E: From<ExpectedValid<'i>>,
     {
-        self.try_advance(|input| input.split_expect(f, expected, "expect"))
+        let endian = self.endian;
+        self.try_advance(|input| input.split_expect(f, endian, expected, "expect"))
     }
 
     /// Expect a value to be read successfully and returned as `Some(O)`.
@@ -167,7 +226,8 @@ where
         E: From<ExpectedValid<'i>>,
         F: FnOnce(&mut Self) -> Result<Option<T>, E>,
     {
-        self.try_advance(|input| input.try_split_expect(f, expected, "try expect"))
+        let endian = self.endian;
+        self.try_advance(|input| input.try_split_expect(f, endian, expected, "try expect"))
     }
 
     /// Expect a value with any error's details erased except for an optional
@@ -216,7 +276,10 @@ where
         F: FnOnce(&mut Self) -> Result<T, R>,
         R: ToRetryRequirement,
     {
-        self.try_advance(|input| input.try_split_expect_erased(f, expected, "try expect erased"))
+        let endian = self.endian;
+        self.try_advance(|input| {
+            input.try_split_expect_erased(f, endian, expected, "try expect erased")
+        })
     }
 
     /// Recovers from an error returning `Some(O)` if successful, or `None` if
@@ -309,8 +372,9 @@ where
     where
         F: FnOnce(&mut Reader<'i, S, I>) -> T,
     {
+        let endian = self.endian;
         self.advance(|input| {
-            let mut sub = Reader::new(input);
+            let mut sub = Reader::with_endian_of(input, endian);
             let ok = f(&mut sub);
             (ok, sub.input)
         })
@@ -553,6 +617,63 @@ where
         )
     }
 
+    /// Read a length of input until the first of any of the literals matches.
+    ///
+    /// Returns a tuple with:
+    ///
+    /// - The input leading up to the literal match.
+    /// - The index of the literal that matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid, pattern};
+    ///
+    /// let delims = pattern::any_literal(&["\r\n", "\n", "\0"]).unwrap();
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello\0").read_all(|r| {
+    ///     let (taken, literal) = r.take_until_literal(&delims)?;
+    ///     r.consume(b'\0')?;
+    ///     Ok((taken, literal))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (dangerous::input(b"hello"), 2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if none of the literals could be found.
+    #[cfg(feature = "aho-corasick")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aho-corasick")))]
+    pub fn take_until_literal(&mut self, literals: &AnyLiteral) -> Result<(I, usize), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| input.split_until_literal(literals, false, "take until literal"))
+    }
+
+    /// Read a length of input until the first of any of the literals matches
+    /// and consumes the matched literal.
+    ///
+    /// Returns a tuple with:
+    ///
+    /// - The input leading up to the literal match.
+    /// - The index of the literal that matched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if none of the literals could be found.
+    #[cfg(feature = "aho-corasick")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "aho-corasick")))]
+    pub fn take_until_consume_literal(&mut self, literals: &AnyLiteral) -> Result<(I, usize), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        self.try_advance(|input| {
+            input.split_until_literal(literals, true, "take until consume literal")
+        })
+    }
+
     /// Peek a length of input.
     ///
     /// The function lifetime `'p` helps prevent the peeked [`Input`] being used
@@ -578,7 +699,7 @@ where
     /// this function if you want an error if there isn't enough input.
     #[must_use = "peek result must be used"]
     #[allow(clippy::needless_lifetimes)]
-    pub fn peek_opt<'p>(&'p self, len: usize) -> Option<Peek<'p, I>> {
+    pub fn peek_opt(&self, len: usize) -> Option<Peek<'_, I>> {
         self.input
             .clone()
             .split_at_opt(len)
@@ -595,6 +716,16 @@ where
         prefix.is_prefix_of(&self.input)
     }
 
+    /// Returns `true` if `prefix` is next in the `Reader`, ignoring ASCII case.
+    #[inline]
+    #[must_use = "peek result must be used"]
+    pub fn peek_eq_ignore_ascii_case<P>(&self, prefix: P) -> bool
+    where
+        P: Prefix<I>,
+    {
+        prefix.is_prefix_of_ignore_ascii_case(&self.input)
+    }
+
     /// Consume expected input.
     ///
     /// Doesn't effect the internal state of the `Reader` if the input couldn't
@@ -612,6 +743,41 @@ where
             .map(drop)
     }
 
+    /// Consume expected input, ignoring ASCII case.
+    ///
+    /// Doesn't effect the internal state of the `Reader` if the input couldn't
+    /// be consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"Content-Length: 5").read_all(|r| {
+    ///     r.consume_ignore_ascii_case(b"content-length")?;
+    ///     r.consume(b": ")?;
+    ///     r.read_u8()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b'5');
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if the input could not be consumed and
+    /// [`ExpectedLength`] if the input matched so far but was too short.
+    pub fn consume_ignore_ascii_case<P>(&mut self, prefix: P) -> Result<(), E>
+    where
+        E: From<ExpectedValue<'i>>,
+        E: From<ExpectedLength<'i>>,
+        P: Prefix<I> + Into<Value<'i>>,
+    {
+        self.try_advance(|input| {
+            input.split_prefix_ignore_ascii_case(prefix, "consume ignoring ASCII case")
+        })
+        .map(drop)
+    }
+
     /// Consume optional input.
     ///
     /// Returns `true` if the input was consumed, `false` if not.
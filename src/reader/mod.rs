@@ -1,14 +1,20 @@
 mod bytes;
+mod chunks;
+mod endian;
 mod input;
 mod peek;
 mod string;
+mod utf16;
 
 use core::marker::PhantomData;
 
 use crate::fmt;
 use crate::input::{Bytes, Input, String};
 
+pub use self::chunks::ChunksReader;
+pub use self::endian::Endian;
 pub use self::peek::Peek;
+pub use self::utf16::Utf16Reader;
 
 /// [`Bytes`] specific [`Reader`].
 pub type BytesReader<'i, E> = Reader<'i, E, Bytes<'i>>;
@@ -44,6 +50,13 @@ pub type StringReader<'i, E> = Reader<'i, E, String<'i>>;
 /// [`context()`] and [`peek_context()`] are provided to add a [`Context`] to
 /// any error thrown inside their scope. This is useful for debugging.
 ///
+/// # Endianness
+///
+/// Alongside the explicit `_le` and `_be` number reading functions, a
+/// [`BytesReader`] provides endian-neutral functions (e.g. `read_u32()`) that
+/// read in the byte order selected with [`with_endian()`]. The byte order
+/// defaults to [`Endian::Big`] and is inherited by any sub-readers.
+///
 /// # Peeking
 ///
 /// Peeking should be used to find the correct path to consume. Values read from
@@ -82,12 +95,14 @@ pub type StringReader<'i, E> = Reader<'i, E, String<'i>>;
 /// [`try_expect_erased()`]: Reader::try_expect_erased()  
 /// [`recover()`]: Reader::recover()  
 /// [`recover_if()`]: Reader::recover_if()  
+/// [`with_endian()`]: Reader::with_endian()  
 /// [`RetryRequirement`]: crate::error::RetryRequirement  
 pub struct Reader<'i, E, I>
 where
     I: Input<'i>,
 {
     input: I,
+    endian: Endian,
     types: PhantomData<(&'i (), E)>,
 }
 
@@ -97,8 +112,14 @@ where
 {
     /// Create a `Reader` given `Input`.
     pub(crate) fn new(input: I) -> Self {
+        Self::with_endian_of(input, Endian::default())
+    }
+
+    /// Create a `Reader` given `Input` and the [`Endian`] it reads with.
+    pub(crate) fn with_endian_of(input: I, endian: Endian) -> Self {
         Self {
             input,
+            endian,
             types: PhantomData,
         }
     }
@@ -23,7 +23,7 @@ pub struct Peek<'p, I> {
     lifetime: PhantomData<&'p ()>,
 }
 
-impl<'p, I> Peek<'p, I> {
+impl<I> Peek<'_, I> {
     #[inline(always)]
     pub(super) fn new(input: I) -> Self {
         Self {
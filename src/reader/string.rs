@@ -1,5 +1,7 @@
-use crate::error::ExpectedLength;
-use crate::input::PrivateExt;
+use crate::error::{ExpectedContext, ExpectedLength, ExpectedValid};
+#[cfg(feature = "unicode")]
+use crate::input::String;
+use crate::input::{is_bidi_control, Input, PrivateExt};
 
 use super::StringReader;
 
@@ -42,4 +44,127 @@ impl<'i, E> StringReader<'i, E> {
     {
         self.try_advance(|input| input.split_first("read char"))
     }
+
+    /// Verify the remaining input has no bidi control characters, without
+    /// consuming it.
+    ///
+    /// Chars with the Unicode `Bidi_Control` property such as `U+202E`
+    /// (RIGHT-TO-LEFT OVERRIDE) reorder how text is displayed, so what is shown
+    /// to a human may differ from what was parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] with a span of the first bidi control char.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Expected, Input};
+    ///
+    /// let input = dangerous::input("access = \"user\u{202E} \u{2066}// admin\u{2069} \u{2066}\"");
+    /// let result: Result<_, Expected<'_>> = input.read_all(|r| {
+    ///     r.verify_no_bidi_controls()?;
+    ///     Ok(r.take_remaining())
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    pub fn verify_no_bidi_controls(&self) -> Result<(), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        let s = self.input.as_dangerous();
+        match s.char_indices().find(|(_, c)| is_bidi_control(*c)) {
+            Some((i, c)) => Err(E::from(ExpectedValid {
+                span: &s.as_bytes()[i..i + c.len_utf8()],
+                input: self.input.clone().into_maybe_string(),
+                context: ExpectedContext {
+                    operation: "verify no bidi controls",
+                    expected: "no bidi control characters",
+                },
+                #[cfg(feature = "retry")]
+                retry_requirement: None,
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// Read an extended grapheme cluster, a user-perceived character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no more input. As a grapheme cluster that
+    /// reaches the end of unbound input may be extended by more input, this
+    /// will also return an error that can be retried in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Expected, Input};
+    ///
+    /// let input = dangerous::input("e\u{301}!");
+    /// let result: Result<_, Expected<'_>> = input.read_partial(|r| r.read_grapheme());
+    ///
+    /// assert_eq!(result.unwrap().0, "e\u{301}"[..]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    pub fn read_grapheme(&mut self) -> Result<String<'i>, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_graphemes(1, "read grapheme"))
+    }
+
+    /// Read `n` extended grapheme clusters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are less than `n` grapheme clusters, or if
+    /// the last reaches the end of unbound input, see [`Self::read_grapheme()`].
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    pub fn take_graphemes(&mut self, n: usize) -> Result<String<'i>, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_graphemes(n, "take graphemes"))
+    }
+
+    /// Read a length of input while each char is `XID_Continue` as defined by
+    /// UAX #31.
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    pub fn take_while_xid_continue(&mut self) -> String<'i> {
+        self.take_while(unicode_xid::UnicodeXID::is_xid_continue)
+    }
+
+    /// Read an identifier as defined by UAX #31, a `XID_Start` char followed
+    /// by any `XID_Continue` chars.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no more input, or [`ExpectedValid`] with
+    /// the offending char as its span if the identifier does not start with a
+    /// `XID_Start` char.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Expected, Input};
+    ///
+    /// let input = dangerous::input("größe = 1");
+    /// let result: Result<_, Expected<'_>> = input.read_partial(|r| r.take_identifier());
+    ///
+    /// assert_eq!(result.unwrap().0, "größe"[..]);
+    /// ```
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unicode")))]
+    pub fn take_identifier(&mut self) -> Result<String<'i>, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_xid_identifier("take identifier"))
+    }
 }
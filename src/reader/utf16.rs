@@ -0,0 +1,231 @@
+use core::marker::PhantomData;
+
+use crate::error::{
+    Context, ExpectedContext, ExpectedLength, ExpectedValid, ExpectedValue, Length, Value,
+    WithContext,
+};
+use crate::fmt;
+use crate::input::Utf16;
+use crate::util::utf16;
+
+/// A reader over [`Utf16`] input.
+///
+/// Each `char` is decoded as it is read, validating surrogate pairs.
+///
+/// # Errors
+///
+/// Functions that decode input return [`ExpectedValid`] on an unpaired
+/// surrogate and [`ExpectedLength`] if a code point is cut short at the end of
+/// the input, which can be retried if the input is not bound.
+pub struct Utf16Reader<'i, E> {
+    input: Utf16<'i>,
+    types: PhantomData<E>,
+}
+
+impl<'i, E> Utf16Reader<'i, E> {
+    /// Create a `Utf16Reader` given [`Utf16`] input.
+    pub(crate) fn new(input: Utf16<'i>) -> Self {
+        Self {
+            input,
+            types: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the reader has no more input to consume.
+    #[must_use]
+    #[inline(always)]
+    pub fn at_end(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    /// Read all of the remaining input.
+    pub fn take_remaining(&mut self) -> Utf16<'i> {
+        let (head, tail) = self.input.clone().split_at_byte(self.input.len());
+        self.input = tail;
+        head
+    }
+
+    /// Mutably use the `Utf16Reader` with a given context.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by the provided function with the specified
+    /// context attached.
+    pub fn context<F, T>(&mut self, context: impl Context, f: F) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let input = self.input.clone().into_hinted_bytes();
+        f(self).map_err(|err| err.with_context(input, context))
+    }
+
+    /// Peek the next `char` in the input without consuming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no more input or the `char` is invalid.
+    pub fn peek_char(&self) -> Result<char, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.decode("peek char").map(|(c, _)| c)
+    }
+
+    /// Peek the next `char` in the input without consuming it.
+    ///
+    /// Returns `None` if there is no more input or the `char` is invalid.
+    #[must_use]
+    pub fn peek_char_opt(&self) -> Option<char> {
+        match utf16::decode_front(self.input.as_dangerous(), self.input.endian()) {
+            Some(Ok((c, _))) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Read a `char`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no more input or the `char` is invalid.
+    pub fn read_char(&mut self) -> Result<char, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let (c, len) = self.decode("read char")?;
+        self.advance(len);
+        Ok(c)
+    }
+
+    /// Read a length of input while the predicate returns `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid `char` is decoded before the predicate
+    /// returns `false`.
+    pub fn take_while<F>(&mut self, mut pred: F) -> Result<Utf16<'i>, E>
+    where
+        F: FnMut(char) -> bool,
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let bytes = self.input.as_dangerous();
+        let mut len = 0;
+        while let Some(result) = utf16::decode_front(&bytes[len..], self.input.endian()) {
+            match result {
+                Ok((c, char_len)) if pred(c) => len += char_len,
+                Ok(_) => break,
+                Err(err) => return Err(self.input.decode_error(&bytes[len..], err, "take while")),
+            }
+        }
+        let (head, tail) = self.input.clone().split_at_byte(len);
+        self.input = tail;
+        Ok(head)
+    }
+
+    /// Skip input while the predicate returns `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an invalid `char` is decoded before the predicate
+    /// returns `false`.
+    pub fn skip_while<F>(&mut self, pred: F) -> Result<(), E>
+    where
+        F: FnMut(char) -> bool,
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.take_while(pred).map(drop)
+    }
+
+    /// Consume the expected `str` encoded as UTF-16.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the encoded `str` could not be consumed from the
+    /// input.
+    pub fn consume(&mut self, expected: &'i str) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>>,
+        E: From<ExpectedValue<'i>>,
+    {
+        let bytes = self.input.as_dangerous();
+        let endian = self.input.endian();
+        let mut offset = 0;
+        for c in expected.chars() {
+            let (encoded, len) = utf16::encode_char(c, endian);
+            let actual = &bytes[offset..];
+            let matched = encoded[..len]
+                .iter()
+                .zip(actual)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if matched == len {
+                offset += len;
+            } else if matched == actual.len() {
+                // All of the input matched but was cut short.
+                return Err(E::from(ExpectedLength {
+                    len: Length::AtLeast(expected.encode_utf16().count() * 2),
+                    span: bytes,
+                    input: self.input.clone().into_maybe_string(),
+                    context: ExpectedContext {
+                        operation: "consume",
+                        expected: "exact value",
+                    },
+                }));
+            } else {
+                // Only the code point that didn't match is reported, encoded
+                // as UTF-16 so it is comparable with the input.
+                return Err(E::from(ExpectedValue {
+                    actual: &actual[..len.min(actual.len())],
+                    expected: Value::from_utf16_char(c, endian),
+                    input: self.input.clone().into_maybe_string(),
+                    context: ExpectedContext {
+                        operation: "consume",
+                        expected: "exact value",
+                    },
+                }));
+            }
+        }
+        self.advance(offset);
+        Ok(())
+    }
+
+    ///////////////////////////////////////////////////////////////////////////
+    // Private
+
+    fn advance(&mut self, len: usize) {
+        self.input = self.input.clone().split_at_byte(len).1;
+    }
+
+    fn decode(&self, operation: &'static str) -> Result<(char, usize), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let bytes = self.input.as_dangerous();
+        match utf16::decode_front(bytes, self.input.endian()) {
+            Some(Ok(ok)) => Ok(ok),
+            Some(Err(err)) => Err(self.input.decode_error(bytes, err, operation)),
+            None => Err(E::from(ExpectedLength {
+                len: Length::AtLeast(2),
+                span: bytes,
+                input: self.input.clone().into_maybe_string(),
+                context: ExpectedContext {
+                    operation,
+                    expected: "enough input",
+                },
+            })),
+        }
+    }
+}
+
+impl<E> fmt::Debug for Utf16Reader<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Utf16Reader")
+            .field("input", &self.input)
+            .finish()
+    }
+}
@@ -0,0 +1,112 @@
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::Decoder;
+
+use crate::{BytesReader, Expected};
+
+use super::{decode_partial, Decoded, StreamError, DEFAULT_MAX_BUFFER_LEN};
+
+/// A `tokio-util` codec [`Decoder`] decoding messages with a parser.
+///
+/// The parser is called on the bytes buffered as unbound input. If the parser
+/// fails with an error that can be retried, `Ok(None)` is returned and the
+/// parser is not called again until the [`RetryRequirement`] is met.
+///
+/// # Example
+///
+/// ```
+/// use bytes::BytesMut;
+/// use dangerous::{BytesReader, Expected};
+/// use dangerous::stream::StreamCodec;
+/// use tokio_util::codec::Decoder;
+///
+/// fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+///     let len = r.read_u8()?;
+///     Ok(r.take(len as usize)?.as_dangerous().to_vec())
+/// }
+///
+/// let mut codec = StreamCodec::new(decode_message);
+/// let mut buf = BytesMut::from(&b"\x03y"[..]);
+///
+/// assert_eq!(codec.decode(&mut buf).unwrap(), None);
+/// buf.extend_from_slice(b"ou\x02");
+/// assert_eq!(codec.decode(&mut buf).unwrap(), Some(b"you".to_vec()));
+/// assert_eq!(&buf[..], b"\x02");
+/// ```
+///
+/// [`RetryRequirement`]: crate::error::RetryRequirement
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-codec")))]
+pub struct StreamCodec<F> {
+    f: F,
+    needed: usize,
+    max_len: usize,
+}
+
+impl<F> StreamCodec<F> {
+    /// Creates a new `StreamCodec` decoding messages with `f`, with a maximum
+    /// buffer length of [`DEFAULT_MAX_BUFFER_LEN`].
+    pub fn new<T>(f: F) -> Self
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        Self {
+            f,
+            needed: 0,
+            max_len: DEFAULT_MAX_BUFFER_LEN,
+        }
+    }
+
+    /// Returns `self` with the maximum number of bytes that will be buffered
+    /// for a single message.
+    #[must_use]
+    pub fn with_max_buffer_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Returns the maximum number of bytes that will be buffered for a single
+    /// message.
+    #[must_use]
+    pub fn max_buffer_len(&self) -> usize {
+        self.max_len
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn require(&mut self, src: &mut BytesMut, needed: usize) -> Result<(), StreamError> {
+        if needed > self.max_len {
+            return Err(StreamError::MessageTooLarge {
+                required: needed,
+                max_len: self.max_len,
+            });
+        }
+        self.needed = needed;
+        src.reserve(needed.saturating_sub(src.len()));
+        Ok(())
+    }
+}
+
+impl<F, T> Decoder for StreamCodec<F>
+where
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    type Item = T;
+    type Error = StreamError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>, StreamError> {
+        if src.is_empty() || src.len() < self.needed {
+            let needed = self.needed.max(src.len() + 1);
+            self.require(src, needed)?;
+            return Ok(None);
+        }
+        match decode_partial(src, &mut self.f)? {
+            Decoded::Message(message, consumed) => {
+                src.advance(consumed);
+                self.needed = 0;
+                Ok(Some(message))
+            }
+            Decoded::Retry(needed) => {
+                self.require(src, needed)?;
+                Ok(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use futures_io::AsyncRead;
+
+use crate::{BytesReader, Expected};
+
+use super::{StreamBuffer, StreamError, DEFAULT_MAX_BUFFER_LEN};
+
+/// Decodes messages from an [`AsyncRead`].
+///
+/// The async counterpart of [`StreamDecoder`], with the reader provided to
+/// each call of [`AsyncStreamDecoder::next_message()`].
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{BytesReader, Expected};
+/// use dangerous::stream::AsyncStreamDecoder;
+///
+/// fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+///     let len = r.read_u8()?;
+///     Ok(r.take(len as usize)?.as_dangerous().to_vec())
+/// }
+///
+/// # futures_executor::block_on(async {
+/// let mut stream: &[u8] = &[2, b'h', b'i', 3, b'y', b'o', b'u'];
+/// let mut decoder = AsyncStreamDecoder::new();
+///
+/// let hi = decoder.next_message(&mut stream, decode_message).await.unwrap();
+/// let you = decoder.next_message(&mut stream, decode_message).await.unwrap();
+/// let end = decoder.next_message(&mut stream, decode_message).await.unwrap();
+///
+/// assert_eq!(hi.unwrap(), b"hi");
+/// assert_eq!(you.unwrap(), b"you");
+/// assert!(end.is_none());
+/// # });
+/// ```
+///
+/// [`StreamDecoder`]: super::StreamDecoder
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+pub struct AsyncStreamDecoder {
+    buf: StreamBuffer,
+}
+
+impl AsyncStreamDecoder {
+    /// Creates a new `AsyncStreamDecoder` with a maximum buffer length of
+    /// [`DEFAULT_MAX_BUFFER_LEN`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            buf: StreamBuffer::new(DEFAULT_MAX_BUFFER_LEN),
+        }
+    }
+
+    /// Returns `self` with the maximum number of bytes that will be buffered
+    /// for a single message.
+    #[must_use]
+    pub fn with_max_buffer_len(mut self, max_len: usize) -> Self {
+        self.buf.set_max_len(max_len);
+        self
+    }
+
+    /// Returns the maximum number of bytes that will be buffered for a single
+    /// message.
+    #[must_use]
+    pub fn max_buffer_len(&self) -> usize {
+        self.buf.max_len()
+    }
+
+    /// Returns the bytes read from the stream not yet consumed by a message.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        self.buf.buffered()
+    }
+
+    /// Decodes the next message with `f`, reading from `reader` until there
+    /// is enough input.
+    ///
+    /// Returns `Ok(None)` if the stream ended cleanly between messages.
+    ///
+    /// # Errors
+    ///
+    /// See [`StreamDecoder::decode()`].
+    ///
+    /// [`StreamDecoder::decode()`]: super::StreamDecoder::decode()
+    pub async fn next_message<R, F, T>(
+        &mut self,
+        reader: &mut R,
+        mut f: F,
+    ) -> Result<Option<T>, StreamError>
+    where
+        R: AsyncRead + Unpin + ?Sized,
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        loop {
+            if let Some(message) = self.buf.try_decode(&mut f)? {
+                return Ok(Some(message));
+            }
+            let read = Read {
+                reader: &mut *reader,
+                buf: self.buf.read_buf()?,
+            };
+            let len = match read.await {
+                Ok(len) => len,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if len == 0 {
+                return if self.buf.buffered().is_empty() {
+                    Ok(None)
+                } else {
+                    Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+                };
+            }
+            self.buf.commit(len);
+        }
+    }
+}
+
+impl Default for AsyncStreamDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Future reading once from an [`AsyncRead`] into a buffer.
+struct Read<'a, R: ?Sized> {
+    reader: &'a mut R,
+    buf: &'a mut [u8],
+}
+
+impl<R> Future for Read<'_, R>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    type Output = io::Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        Pin::new(&mut *this.reader).poll_read(cx, this.buf)
+    }
+}
@@ -0,0 +1,249 @@
+//! Streaming support.
+//!
+//! Messages are decoded from a growable buffer filled from a stream. The
+//! buffer is passed to the parser as unbound [`Bytes`], and when the parser
+//! fails with an error that can be retried, the [`RetryRequirement`] is used
+//! to decide how many more bytes must be read before the parser is retried.
+//! Bytes consumed by decoded messages are compacted out of the buffer before
+//! more is read.
+//!
+//! Parsers are provided the [`Expected`] error so that an error which can't
+//! be retried can be returned as an [`OwnedExpected`], escaping the lifetime of
+//! the buffer. As the buffer is reused between messages, decoded messages must
+//! own their values.
+//!
+//! - [`StreamDecoder`] decodes messages from an [`std::io::Read`].
+//! - `AsyncStreamDecoder` decodes messages from a `futures-io`
+//!   `AsyncRead`, if the `futures-io` feature is enabled.
+//! - `StreamCodec` decodes messages as a `tokio-util` codec `Decoder`, if the
+//!   `tokio-codec` feature is enabled.
+//!
+//! [`Bytes`]: crate::Bytes
+//! [`Expected`]: crate::Expected
+//! [`RetryRequirement`]: crate::error::RetryRequirement
+
+#[cfg(feature = "tokio-codec")]
+mod codec;
+#[cfg(feature = "futures-io")]
+mod futures;
+mod read;
+
+use alloc::vec::Vec;
+use std::io;
+
+use crate::error::OwnedExpected;
+use crate::fmt;
+use crate::{BytesReader, Expected, Input, ToRetryRequirement};
+
+#[cfg(feature = "tokio-codec")]
+pub use self::codec::StreamCodec;
+#[cfg(feature = "futures-io")]
+pub use self::futures::AsyncStreamDecoder;
+pub use self::read::{Messages, StreamDecoder};
+
+/// The default maximum number of bytes a stream decoder will buffer.
+pub const DEFAULT_MAX_BUFFER_LEN: usize = 64 * 1024;
+
+/// The minimum number of bytes read from a stream at a time.
+const READ_CHUNK_LEN: usize = 4 * 1024;
+
+/// An error decoding a message from a stream.
+#[must_use = "error must be handled"]
+pub enum StreamError {
+    /// Reading from the stream failed.
+    ///
+    /// If the stream ended part way through a message, the error kind is
+    /// [`io::ErrorKind::UnexpectedEof`].
+    Io(io::Error),
+    /// The message was invalid and could not be retried with more input.
+    Invalid(OwnedExpected),
+    /// The message requires more bytes than the maximum buffer length.
+    MessageTooLarge {
+        /// The number of bytes the message requires at least.
+        required: usize,
+        /// The maximum buffer length configured.
+        max_len: usize,
+    },
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl fmt::Debug for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => f.debug_tuple("Io").field(err).finish(),
+            Self::Invalid(err) => f.debug_tuple("Invalid").field(err).finish(),
+            Self::MessageTooLarge { required, max_len } => f
+                .debug_struct("MessageTooLarge")
+                .field("required", required)
+                .field("max_len", max_len)
+                .finish(),
+        }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => {
+                f.write_str("failed to read from stream: ")?;
+                fmt::Display::fmt(err, f)
+            }
+            Self::Invalid(err) => fmt::Display::fmt(err, f),
+            Self::MessageTooLarge { required, max_len } => {
+                f.write_str("message requires at least ")?;
+                fmt::Display::fmt(required, f)?;
+                f.write_str(" bytes which exceeds the maximum buffer length of ")?;
+                fmt::Display::fmt(max_len, f)?;
+                f.write_str(" bytes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Invalid(err) => Some(err),
+            Self::MessageTooLarge { .. } => None,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Stream buffer
+
+/// A growable buffer of input read from a stream, shared by the decoders.
+pub(crate) struct StreamBuffer {
+    buf: Vec<u8>,
+    /// The start of the bytes not yet consumed.
+    start: usize,
+    /// The end of the bytes read.
+    end: usize,
+    /// The number of bytes from `start` required before decoding is retried.
+    needed: usize,
+    /// The total number of bytes consumed by the messages decoded.
+    consumed: u64,
+    max_len: usize,
+}
+
+impl StreamBuffer {
+    pub(crate) fn new(max_len: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            start: 0,
+            end: 0,
+            needed: 0,
+            consumed: 0,
+            max_len,
+        }
+    }
+
+    /// Returns the total number of bytes consumed by the messages decoded.
+    pub(crate) fn consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    pub(crate) fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    pub(crate) fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len;
+    }
+
+    /// Returns the bytes read but not yet consumed.
+    pub(crate) fn buffered(&self) -> &[u8] {
+        &self.buf[self.start..self.end]
+    }
+
+    /// Tries to decode a message from the buffered bytes.
+    ///
+    /// Returns `Ok(None)` if more bytes must be read before retrying.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn try_decode<F, T>(&mut self, f: &mut F) -> Result<Option<T>, StreamError>
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        let available = self.end - self.start;
+        if available == 0 || available < self.needed {
+            return Ok(None);
+        }
+        match decode_partial(&self.buf[self.start..self.end], f)? {
+            Decoded::Message(message, consumed) => {
+                self.start += consumed;
+                self.consumed += consumed as u64;
+                self.needed = 0;
+                Ok(Some(message))
+            }
+            Decoded::Retry(needed) => {
+                self.needed = needed;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Compacts the consumed bytes out of the buffer and returns the space
+    /// to read more bytes into.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StreamError::MessageTooLarge`] if more bytes are required
+    /// than the maximum buffer length.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn read_buf(&mut self) -> Result<&mut [u8], StreamError> {
+        if self.start > 0 {
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+        let required = self.needed.max(self.end + 1);
+        if required > self.max_len {
+            return Err(StreamError::MessageTooLarge {
+                required,
+                max_len: self.max_len,
+            });
+        }
+        let target = required.max(self.end + READ_CHUNK_LEN).min(self.max_len);
+        if self.buf.len() < target {
+            self.buf.resize(target, 0);
+        }
+        Ok(&mut self.buf[self.end..])
+    }
+
+    /// Marks `len` bytes as read into the space returned by
+    /// [`StreamBuffer::read_buf()`].
+    pub(crate) fn commit(&mut self, len: usize) {
+        self.end += len;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// The result of decoding a message from a partial stream of bytes.
+pub(crate) enum Decoded<T> {
+    /// The message was decoded, consuming the number of bytes.
+    Message(T, usize),
+    /// The number of bytes required in total before decoding is retried.
+    Retry(usize),
+}
+
+/// Decodes a message from the start of `bytes` as unbound input.
+#[allow(clippy::result_large_err)]
+pub(crate) fn decode_partial<F, T>(bytes: &[u8], f: &mut F) -> Result<Decoded<T>, StreamError>
+where
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    match crate::input(bytes).read_partial(|r| f(r)) {
+        Ok((message, remaining)) => Ok(Decoded::Message(message, bytes.len() - remaining.len())),
+        Err(err) => match err.to_retry_requirement() {
+            Some(requirement) => Ok(Decoded::Retry(bytes.len() + requirement.continue_after())),
+            None => Err(StreamError::Invalid(err.into_owned())),
+        },
+    }
+}
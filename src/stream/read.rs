@@ -0,0 +1,162 @@
+use core::iter::FusedIterator;
+use std::io;
+
+use crate::{BytesReader, Expected};
+
+use super::{StreamBuffer, StreamError, DEFAULT_MAX_BUFFER_LEN};
+
+/// Decodes messages from an [`io::Read`].
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{BytesReader, Expected};
+/// use dangerous::stream::StreamDecoder;
+///
+/// fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+///     let len = r.read_u8()?;
+///     Ok(r.take(len as usize)?.as_dangerous().to_vec())
+/// }
+///
+/// let stream: &[u8] = &[2, b'h', b'i', 3, b'y', b'o', b'u'];
+/// let mut decoder = StreamDecoder::new(stream);
+///
+/// let messages: Vec<_> = decoder.messages(decode_message).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(messages, [&b"hi"[..], b"you"]);
+/// ```
+pub struct StreamDecoder<R> {
+    read: R,
+    buf: StreamBuffer,
+}
+
+impl<R> StreamDecoder<R>
+where
+    R: io::Read,
+{
+    /// Creates a new `StreamDecoder` reading from `read`, with a maximum
+    /// buffer length of [`DEFAULT_MAX_BUFFER_LEN`].
+    pub fn new(read: R) -> Self {
+        Self {
+            read,
+            buf: StreamBuffer::new(DEFAULT_MAX_BUFFER_LEN),
+        }
+    }
+
+    /// Returns `self` with the maximum number of bytes that will be buffered
+    /// for a single message.
+    #[must_use]
+    pub fn with_max_buffer_len(mut self, max_len: usize) -> Self {
+        self.buf.set_max_len(max_len);
+        self
+    }
+
+    /// Returns the maximum number of bytes that will be buffered for a single
+    /// message.
+    #[must_use]
+    pub fn max_buffer_len(&self) -> usize {
+        self.buf.max_len()
+    }
+
+    /// Returns the bytes read from the stream not yet consumed by a message.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        self.buf.buffered()
+    }
+
+    /// Consumes `self` into the underlying reader.
+    ///
+    /// Any bytes buffered are lost.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+
+    /// Decodes the next message with `f`, reading from the stream until there
+    /// is enough input.
+    ///
+    /// Returns `Ok(None)` if the stream ended cleanly between messages.
+    ///
+    /// # Errors
+    ///
+    /// - [`StreamError::Io`] if reading failed, or if the stream ended part
+    ///   way through a message.
+    /// - [`StreamError::Invalid`] if the message was invalid. Calling `decode`
+    ///   again will return the same error as the invalid input is not
+    ///   consumed.
+    /// - [`StreamError::MessageTooLarge`] if the message requires more bytes
+    ///   than the maximum buffer length.
+    #[allow(clippy::result_large_err)]
+    pub fn decode<F, T>(&mut self, mut f: F) -> Result<Option<T>, StreamError>
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        loop {
+            if let Some(message) = self.buf.try_decode(&mut f)? {
+                return Ok(Some(message));
+            }
+            let len = match self.read.read(self.buf.read_buf()?) {
+                Ok(len) => len,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if len == 0 {
+                return if self.buf.buffered().is_empty() {
+                    Ok(None)
+                } else {
+                    Err(io::Error::from(io::ErrorKind::UnexpectedEof).into())
+                };
+            }
+            self.buf.commit(len);
+        }
+    }
+
+    /// Returns an iterator decoding messages with `f` until the stream ends.
+    ///
+    /// The iterator ends after the first error, or after a message that
+    /// consumed no input as decoding would otherwise return the same message
+    /// forever.
+    pub fn messages<F, T>(&mut self, f: F) -> Messages<'_, R, F>
+    where
+        F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+    {
+        Messages {
+            decoder: self,
+            f,
+            done: false,
+        }
+    }
+}
+
+/// An iterator over messages decoded from a [`StreamDecoder`].
+///
+/// Created with [`StreamDecoder::messages()`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Messages<'a, R, F> {
+    decoder: &'a mut StreamDecoder<R>,
+    f: F,
+    done: bool,
+}
+
+impl<R, F, T> Iterator for Messages<'_, R, F>
+where
+    R: io::Read,
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    type Item = Result<T, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let consumed = self.decoder.buf.consumed();
+        let result = self.decoder.decode(&mut self.f).transpose();
+        self.done = !matches!(result, Some(Ok(_))) || self.decoder.buf.consumed() == consumed;
+        result
+    }
+}
+
+impl<R, F, T> FusedIterator for Messages<'_, R, F>
+where
+    R: io::Read,
+    F: for<'i> FnMut(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+}
@@ -0,0 +1,231 @@
+//! Property testing of parsers.
+//!
+//! [`check()`] runs a parser over some input and its prefixes, asserting
+//! invariants every parser built with this crate should uphold, whatever the
+//! format it reads:
+//!
+//! - The parser does not panic.
+//! - Error spans are within the error input, and the error input within the
+//!   input provided.
+//! - [`Input::read_all()`] and [`Input::read_partial()`] agree.
+//! - Retry requirements are honoured. No prefix of input that could be read
+//!   fails fatally, no prefix is read before the requirement reported for a
+//!   shorter one is met, and feeding exactly the bytes required never yields
+//!   the same error again.
+//!
+//! Input can come from anywhere, such as a fuzzer, [`arbitrary`] or
+//! [`proptest`] with [`check_with_proptest()`] if the feature is enabled.
+//!
+//! [`arbitrary`]: https://docs.rs/arbitrary
+//! [`proptest`]: https://docs.rs/proptest
+
+use core::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::error::{Details, Expected, RetryRequirement, ToRetryRequirement};
+use crate::{BytesReader, Input};
+
+/// Checks a parser upholds the invariants described in the
+/// [module documentation](self).
+///
+/// The value read is discarded. Parsers returning input borrowed from the
+/// reader can be checked by mapping it away, for example with `.map(drop)`.
+///
+/// Every prefix of `input` is parsed, so keep inputs small.
+///
+/// # Example
+///
+/// ```
+/// dangerous::testing::check(b"\x02hi", |r| {
+///     let len = r.read_u8()?;
+///     r.take(len as usize).map(drop)
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics describing the first invariant violated.
+pub fn check<F, T>(input: &[u8], f: F)
+where
+    F: for<'i> Fn(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    let prefixes: Vec<_> = (0..=input.len())
+        .map(|len| read(input, len, false, &f))
+        .collect();
+    check_read_all(
+        input,
+        &prefixes[input.len()],
+        &read(input, input.len(), true, &f),
+    );
+    for (len, prefix) in prefixes.iter().enumerate() {
+        check_retry(input, len, prefix, &prefixes);
+    }
+}
+
+/// Checks a parser upholds the invariants described in the
+/// [module documentation](self) with input generated by `proptest`.
+///
+/// The test runner is configured from the environment as with the
+/// `proptest!` macro, and the input is shrunk if an invariant is violated.
+///
+/// # Example
+///
+/// ```
+/// dangerous::testing::check_with_proptest(|r| {
+///     let len = r.read_u8()?;
+///     r.take(len as usize).map(drop)
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics with the minimal input found to violate an invariant.
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub fn check_with_proptest<F, T>(f: F)
+where
+    F: for<'i> Fn(&mut BytesReader<'i, Expected<'i>>) -> Result<T, Expected<'i>>,
+{
+    use proptest::arbitrary::any;
+    use proptest::test_runner::TestRunner;
+
+    let result = TestRunner::default().run(&any::<Vec<u8>>(), |input| {
+        check(&input, &f);
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("{}", err);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Invariants
+
+/// The outcome of reading a prefix of input.
+enum Outcome<'i> {
+    /// Read leaving a number of bytes.
+    Read(usize),
+    /// Failed with an error.
+    Failed(Expected<'i>),
+}
+
+/// Reads a prefix of `input`, checking the parser does not panic and any
+/// error is within the input.
+#[allow(clippy::result_large_err)]
+fn read<'i, F, T>(input: &'i [u8], len: usize, all: bool, f: &F) -> Outcome<'i>
+where
+    F: for<'a> Fn(&mut BytesReader<'a, Expected<'a>>) -> Result<T, Expected<'a>>,
+{
+    let operation = if all { "read all" } else { "read partial" };
+    let prefix = crate::input(&input[..len]);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        if all {
+            prefix.clone().read_all(f).map(|_| 0)
+        } else {
+            prefix.clone().read_partial(f).map(|(_, rest)| rest.len())
+        }
+    }))
+    .unwrap_or_else(|_| panic!("{} of {:?} panicked", operation, prefix));
+    match result {
+        Ok(left) => Outcome::Read(left),
+        Err(err) => {
+            let err_input = err.input().into_bytes();
+            assert!(
+                err_input.is_within(&prefix),
+                "{} of {:?} returned an error with input not within it:\n{}",
+                operation,
+                prefix,
+                err
+            );
+            assert!(
+                err.span().is_within(&err_input),
+                "{} of {:?} returned an error with a span not within its input:\n{}",
+                operation,
+                prefix,
+                err
+            );
+            Outcome::Failed(err)
+        }
+    }
+}
+
+/// Checks `read_all` agrees with `read_partial` on the same input.
+fn check_read_all(input: &[u8], partial: &Outcome<'_>, all: &Outcome<'_>) {
+    let consistent = match (partial, all) {
+        (Outcome::Read(left), Outcome::Read(_)) => *left == 0,
+        (Outcome::Read(left), Outcome::Failed(_)) => *left != 0,
+        (Outcome::Failed(partial), Outcome::Failed(all)) => {
+            partial.to_retry_requirement() == all.to_retry_requirement()
+        }
+        (Outcome::Failed(_), Outcome::Read(_)) => false,
+    };
+    assert!(
+        consistent,
+        "read all of {:?} {} but read partial {}",
+        crate::input(input),
+        describe(all),
+        describe(partial)
+    );
+}
+
+/// Checks the error reading the prefix of `len` bytes agrees with the
+/// longer prefixes.
+fn check_retry(input: &[u8], len: usize, prefix: &Outcome<'_>, prefixes: &[Outcome<'_>]) {
+    if let Outcome::Failed(err) = prefix {
+        match err.to_retry_requirement() {
+            Some(requirement) => check_requirement(input, len, err, requirement, prefixes),
+            None => assert!(
+                !matches!(prefixes[input.len()], Outcome::Read(_)),
+                "read partial of {:?} failed fatally but the full input was read:\n{}",
+                crate::input(&input[..len]),
+                err
+            ),
+        }
+    }
+}
+
+/// Checks the retry requirement of the prefix of `len` bytes is honoured by
+/// the longer prefixes.
+fn check_requirement(
+    input: &[u8],
+    len: usize,
+    err: &Expected<'_>,
+    requirement: RetryRequirement,
+    prefixes: &[Outcome<'_>],
+) {
+    let required = len.saturating_add(requirement.continue_after());
+    for (longer, outcome) in prefixes.iter().enumerate().take(required).skip(len + 1) {
+        assert!(
+            !matches!(outcome, Outcome::Read(_)),
+            "read partial of {:?} required {} but {:?} was read:\n{}",
+            crate::input(&input[..len]),
+            requirement,
+            crate::input(&input[..longer]),
+            err
+        );
+    }
+    if let Some(Outcome::Failed(next)) = prefixes.get(required) {
+        let same = next.to_retry_requirement() == Some(requirement)
+            && span_range(input, next) == span_range(input, err);
+        assert!(
+            !same,
+            "read partial of {:?} required {} but fed exactly that failed the same again:\n{}",
+            crate::input(&input[..len]),
+            requirement,
+            next
+        );
+    }
+}
+
+fn span_range(input: &[u8], err: &Expected<'_>) -> Option<Range<usize>> {
+    err.span().span_of(&crate::input(input))
+}
+
+fn describe(outcome: &Outcome<'_>) -> &'static str {
+    match outcome {
+        Outcome::Read(0) => "was read",
+        Outcome::Read(_) => "was read with input left",
+        Outcome::Failed(err) if err.is_fatal() => "failed fatally",
+        Outcome::Failed(_) => "failed needing more input",
+    }
+}
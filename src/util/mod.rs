@@ -1,2 +1,3 @@
 pub(crate) mod slice;
+pub(crate) mod utf16;
 pub(crate) mod utf8;
@@ -57,3 +57,22 @@ pub(crate) unsafe fn split_str_at_unchecked(slice: &str, mid: usize) -> (&str, &
     debug_assert!(slice.is_char_boundary(mid));
     (slice.get_unchecked(..mid), slice.get_unchecked(mid..))
 }
+
+/// Compares two slices of equal length without exiting early on the first
+/// byte that differs.
+///
+/// Returns `false` if the lengths differ, which is not hidden.
+#[inline(never)]
+pub(crate) fn ct_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut diff = 0;
+    for (l, r) in left.iter().zip(right.iter()) {
+        // Hiding the accumulator from the optimiser on every iteration keeps
+        // it from exiting the loop early once a difference is found. This is
+        // best effort and not a guarantee of constant time execution.
+        diff = core::hint::black_box(diff | (l ^ r));
+    }
+    diff == 0
+}
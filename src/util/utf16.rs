@@ -0,0 +1,138 @@
+use crate::reader::Endian;
+
+const SURROGATE_MIN: u16 = 0xD800;
+const LOW_SURROGATE_MIN: u16 = 0xDC00;
+const SURROGATE_MAX: u16 = 0xDFFF;
+
+/// An error decoding a UTF-16 code point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum InvalidUnit {
+    /// The code point is incomplete and requires the total number of bytes.
+    Incomplete(usize),
+    /// An unpaired surrogate code unit.
+    Unpaired,
+}
+
+#[inline(always)]
+pub(crate) fn unit(bytes: [u8; 2], endian: Endian) -> u16 {
+    match endian {
+        Endian::Big => u16::from_be_bytes(bytes),
+        Endian::Little => u16::from_le_bytes(bytes),
+    }
+}
+
+#[inline(always)]
+fn is_high_surrogate(unit: u16) -> bool {
+    (SURROGATE_MIN..LOW_SURROGATE_MIN).contains(&unit)
+}
+
+#[inline(always)]
+fn is_low_surrogate(unit: u16) -> bool {
+    (LOW_SURROGATE_MIN..=SURROGATE_MAX).contains(&unit)
+}
+
+/// Returns `true` if the bytes start at a UTF-16 code point boundary.
+pub(crate) fn is_boundary(bytes: &[u8], endian: Endian) -> bool {
+    match bytes {
+        [a, b, ..] => !is_low_surrogate(unit([*a, *b], endian)),
+        _ => true,
+    }
+}
+
+/// Decodes the first UTF-16 code point and returns it with its byte length.
+pub(crate) fn decode_front(
+    bytes: &[u8],
+    endian: Endian,
+) -> Option<Result<(char, usize), InvalidUnit>> {
+    let first = match bytes {
+        [] => return None,
+        [_] => return Some(Err(InvalidUnit::Incomplete(2))),
+        [a, b, ..] => unit([*a, *b], endian),
+    };
+    if is_low_surrogate(first) {
+        return Some(Err(InvalidUnit::Unpaired));
+    }
+    if !is_high_surrogate(first) {
+        // SAFETY: the unit is not a surrogate so it is a valid code point.
+        return Some(Ok((
+            unsafe { char::from_u32_unchecked(u32::from(first)) },
+            2,
+        )));
+    }
+    let second = match bytes {
+        [_, _, a, b, ..] => unit([*a, *b], endian),
+        _ => return Some(Err(InvalidUnit::Incomplete(4))),
+    };
+    if is_low_surrogate(second) {
+        Some(Ok((pair(first, second), 4)))
+    } else {
+        Some(Err(InvalidUnit::Unpaired))
+    }
+}
+
+/// Decodes the last UTF-16 code point and returns it with its byte length.
+pub(crate) fn decode_back(bytes: &[u8], endian: Endian) -> Option<Result<(char, usize), ()>> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let last = match bytes {
+        [.., a, b] if bytes.len() & 1 == 0 => unit([*a, *b], endian),
+        _ => return Some(Err(())),
+    };
+    if is_high_surrogate(last) {
+        return Some(Err(()));
+    }
+    if !is_low_surrogate(last) {
+        // SAFETY: the unit is not a surrogate so it is a valid code point.
+        return Some(Ok((
+            unsafe { char::from_u32_unchecked(u32::from(last)) },
+            2,
+        )));
+    }
+    match bytes {
+        [.., a, b, _, _] if is_high_surrogate(unit([*a, *b], endian)) => {
+            Some(Ok((pair(unit([*a, *b], endian), last), 4)))
+        }
+        _ => Some(Err(())),
+    }
+}
+
+/// Returns `true` if all of the bytes are valid UTF-16.
+pub(crate) fn is_valid(mut bytes: &[u8], endian: Endian) -> bool {
+    while let Some(result) = decode_front(bytes, endian) {
+        match result {
+            Ok((_, len)) => bytes = &bytes[len..],
+            Err(_) => return false,
+        }
+    }
+    true
+}
+
+/// Returns an iterator over the bytes of a str encoded as UTF-16.
+pub(crate) fn encode_str(s: &str, endian: Endian) -> impl Iterator<Item = u8> + '_ {
+    s.encode_utf16().flat_map(move |unit| match endian {
+        Endian::Big => unit.to_be_bytes(),
+        Endian::Little => unit.to_le_bytes(),
+    })
+}
+
+/// Encodes a char as UTF-16, returning the buffer and the encoded length.
+pub(crate) fn encode_char(c: char, endian: Endian) -> ([u8; 4], usize) {
+    let mut units = [0; 2];
+    let units = c.encode_utf16(&mut units);
+    let mut buf = [0; 4];
+    for (chunk, unit) in buf.chunks_exact_mut(2).zip(units.iter()) {
+        chunk.copy_from_slice(&match endian {
+            Endian::Big => unit.to_be_bytes(),
+            Endian::Little => unit.to_le_bytes(),
+        });
+    }
+    (buf, units.len() * 2)
+}
+
+#[inline(always)]
+fn pair(high: u16, low: u16) -> char {
+    let c = 0x1_0000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00);
+    // SAFETY: a high and low surrogate pair always encodes a valid code point.
+    unsafe { char::from_u32_unchecked(c) }
+}
@@ -168,7 +168,7 @@ impl<'i> CharIter<'i> {
     }
 }
 
-impl<'i> Iterator for CharIter<'i> {
+impl Iterator for CharIter<'_> {
     type Item = Result<char, InvalidChar>;
 
     #[inline]
@@ -195,7 +195,7 @@ impl<'i> Iterator for CharIter<'i> {
     }
 }
 
-impl<'i> DoubleEndedIterator for CharIter<'i> {
+impl DoubleEndedIterator for CharIter<'_> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.is_done() {
@@ -1,4 +1,4 @@
-#![allow(unused_macros)]
+#![allow(unused_macros, unused_imports)]
 
 pub use dangerous::{error::*, *};
 pub use indoc::indoc;
@@ -0,0 +1,118 @@
+#![allow(clippy::result_large_err)]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::checksum::{Adler32, Checksum, Crc16, Crc32, Sum8, Xor8};
+
+fn check<C: Checksum>(mut checksum: C) -> u64 {
+    checksum.update(b"1234");
+    checksum.update(b"56789");
+    checksum.finish()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test check values
+
+#[test]
+fn test_check_values() {
+    assert_eq!(check(Crc32::ieee()), 0xcbf4_3926);
+    assert_eq!(check(Crc32::castagnoli()), 0xe306_9283);
+    assert_eq!(check(Crc16::ccitt_false()), 0x29b1);
+    assert_eq!(check(Crc16::xmodem()), 0x31c3);
+    assert_eq!(check(Adler32::new()), 0x091e_01de);
+    assert_eq!(check(Xor8::new()), 0x31);
+    assert_eq!(check(Sum8::new()), 0xdd);
+}
+
+#[test]
+fn test_adler32_long_input() {
+    let mut checksum = Adler32::new();
+    checksum.update(&[0xff; 100_000]);
+    assert_eq!(checksum.finish(), 0x149a_302c);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test BytesReader::verify_checksum
+
+#[test]
+fn test_verify_checksum_ok() {
+    let value = read_all_ok!(b"123456789\xcb\xf4\x39\x26", |r| {
+        r.verify_checksum(Crc32::ieee(), |r| r.take(9))
+    });
+    assert_eq!(value, b"123456789"[..]);
+}
+
+#[test]
+fn test_verify_checksum_little_endian() {
+    let value = read_all_ok!(b"123456789\xb1\x29", |r| {
+        r.with_endian(Endian::Little, |r| {
+            r.verify_checksum(Crc16::ccitt_false(), |r| r.take(9))
+        })
+    });
+    assert_eq!(value, b"123456789"[..]);
+}
+
+#[test]
+fn test_verify_checksum_by_mut_ref() {
+    let mut checksum = Sum8::new();
+    let value = read_all_ok!(b"123456789\xdd", |r| {
+        r.verify_checksum(&mut checksum, |r| r.take(9))
+    });
+    assert_eq!(value, b"123456789"[..]);
+    assert_eq!(checksum.finish(), 0xdd);
+}
+
+#[test]
+fn test_verify_checksum_mismatch() {
+    let error = read_all_err!(b"123456789\x00", |r| {
+        r.verify_checksum(Xor8::new(), |r| r.take(9))
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), None);
+    assert_eq!(error.span(), b"\x00"[..]);
+    assert_eq!(error.expected().unwrap().as_bytes(), b"\x31");
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_verify_checksum_mismatch_display() {
+    let error = read_all_err!(b"123456789\x00", |r| {
+        r.verify_checksum(Xor8::new(), |r| r.take(9))
+    });
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to read checksum: found a different value to the exact expected
+            expected:
+            > [31]
+            in:
+            > [31 32 33 34 35 36 37 38 39 00]
+                                          ^^ 
+            additional:
+              error offset: 9, input length: 10
+            backtrace:
+              1. `read all`
+              2. `verify checksum`
+              3. `read checksum` (expected matching checksum)
+        "#}
+    );
+}
+
+#[test]
+fn test_verify_checksum_missing() {
+    let error = read_all_err!(b"123456789\xcb\xf4", |r| {
+        r.verify_checksum(Crc32::ieee(), |r| r.take(9))
+    });
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_verify_checksum_fn_error() {
+    let error = read_all_err!(b"12", |r| {
+        r.verify_checksum(Crc32::ieee(), |r| r.take(9))
+    });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(7));
+}
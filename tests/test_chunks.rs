@@ -0,0 +1,183 @@
+#![allow(clippy::result_large_err)]
+
+#[macro_use]
+mod common;
+
+use common::*;
+
+const CHUNKS: &[&[u8]] = &[b"\x01", b"", b"\x02\x03", b"\x04hello ", b"wor", b"ld"];
+
+fn chunks() -> Chunks<'static> {
+    Chunks::new(CHUNKS)
+}
+
+#[test]
+fn test_chunks_slices() {
+    let chunks = chunks();
+
+    assert_eq!(chunks.len(), 15);
+    assert_eq!(chunks.offset(), 0);
+    assert_eq!(chunks, &b"\x01\x02\x03\x04hello world"[..]);
+    assert_eq!(chunks.slices().count(), 5);
+    assert!(chunks.as_contiguous().is_none());
+    assert!(Chunks::new(&[]).is_empty());
+}
+
+#[test]
+fn test_chunks_debug() {
+    assert_eq!(
+        format!("{:?}", Chunks::new(&[b"ab", b"c"])),
+        "Chunks { bound: Start, offset: 0, slices: [[61 62], [63]] }"
+    );
+}
+
+#[test]
+fn test_read_num_across_chunks() {
+    let value = chunks()
+        .read_partial::<_, _, Expected>(|r| r.read_u32_be())
+        .unwrap();
+    assert_eq!(value.0, 0x0102_0304);
+    assert_eq!(value.1.offset(), 4);
+
+    let value = chunks()
+        .read_partial::<_, _, Expected>(|r| Ok((r.read_u8()?, r.read_u16_le()?)))
+        .unwrap();
+    assert_eq!(value.0, (0x01, 0x0302));
+
+    let value = Chunks::new(&[&[0xff; 9], b"", &[0x01; 7]])
+        .read_all::<_, _, Expected>(|r| r.read_u128_le())
+        .unwrap();
+    assert_eq!(value, 0x0101_0101_0101_01ff_ffff_ffff_ffff_ffff);
+
+    let value = chunks()
+        .read_partial::<_, _, Expected>(|r| r.read_f32_be())
+        .unwrap();
+    assert_eq!(value.0, f32::from_bits(0x0102_0304));
+}
+
+#[test]
+fn test_take_until_across_chunks() {
+    let (hello, world) = chunks()
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(4)?;
+            let hello = r.take_until_consume(b' ')?;
+            Ok((hello, r.take_remaining()))
+        })
+        .unwrap();
+
+    assert_eq!(hello, &b"hello"[..]);
+    assert_eq!(hello.offset(), 4);
+    assert_eq!(hello.as_contiguous().unwrap(), b"hello"[..]);
+    assert_eq!(world, &b"world"[..]);
+    assert_eq!(world.offset(), 10);
+    assert!(world.as_contiguous().is_none());
+    #[cfg(feature = "alloc")]
+    {
+        use std::borrow::Cow;
+        assert!(matches!(hello.to_contiguous(), Cow::Borrowed(b"hello")));
+        assert!(matches!(world.to_contiguous(), Cow::Owned(v) if v == b"world"));
+    }
+}
+
+#[test]
+fn test_consume_across_chunks() {
+    let position = chunks()
+        .read_partial::<_, _, Expected>(|r| {
+            r.consume(b"\x01\x02\x03\x04hello wo")?;
+            Ok(r.position())
+        })
+        .unwrap()
+        .0;
+    assert_eq!(position, 12);
+
+    let error = chunks()
+        .read_all::<_, _, Expected>(|r| r.consume(b"\x01\x02\x03\x04help"))
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"l"[..]);
+}
+
+#[test]
+fn test_consume_borrowed_for_less_than_input() {
+    let expected = b"\x01\x02\x03\x04hello".to_vec();
+    let position = chunks()
+        .read_partial::<_, _, Expected>(|r| {
+            r.consume(&expected)?;
+            Ok(r.position())
+        })
+        .unwrap()
+        .0;
+    assert_eq!(position, 9);
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_retry_across_chunks() {
+    let error = chunks()
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(12)?;
+            r.read_u64_be()
+        })
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(5));
+
+    let error = chunks()
+        .read_all::<_, _, Expected>(|r| r.consume(b"\x01\x02\x03\x04hello worlds"))
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+
+    let error = chunks()
+        .into_bound()
+        .read_all::<_, _, Expected>(|r| r.take(16))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_trailing_input() {
+    let error = chunks()
+        .read_all::<_, _, Expected>(|r| r.skip(5))
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"ello "[..]);
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_error_chunk_context() {
+    let error = chunks()
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(4)?;
+            r.context("greeting", |r| r.consume(b"hello!"))
+        })
+        .unwrap_err();
+
+    let mut chunk = None;
+    error.context_stack().walk(&mut |_, context| {
+        if let Some(context) = context.as_any().downcast_ref::<ChunkContext>() {
+            chunk = Some(*context);
+        }
+        true
+    });
+    let chunk = chunk.unwrap();
+    assert_eq!(chunk.index(), 3);
+    assert_eq!(chunk.offset(), 3);
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to consume: found a different value to the exact expected
+            expected:
+            > [21]
+            in:
+            > [04 68 65 6c 6c 6f 20]
+                                 ^^ 
+            additional:
+              error offset: 6, input length: 7
+            backtrace:
+              1. `read all`
+              2. `read` (expected greeting)
+              3. `read chunks` (expected chunk 3 at stream offset 3)
+              4. `consume` (expected exact value)
+        "#}
+    );
+}
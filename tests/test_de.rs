@@ -0,0 +1,201 @@
+#![allow(clippy::result_large_err)]
+#![cfg(all(feature = "serde", feature = "retry"))]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::de::{self, Postcard};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Header<'a> {
+    name: &'a str,
+    #[serde(borrow)]
+    flags: Flags<'a>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Flags<'a> {
+    enabled: bool,
+    label: Option<&'a str>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(u32),
+    Line(i16, i16),
+    Rect { w: u8, h: u8 },
+}
+
+fn deserialize_ok<'i, T>(bytes: &'i [u8]) -> T
+where
+    T: Deserialize<'i>,
+{
+    dangerous::input(bytes)
+        .read_all::<_, _, Expected<'_>>(|r| de::deserialize(r, Postcard))
+        .unwrap()
+}
+
+fn deserialize_err<'i, T>(bytes: &'i [u8]) -> Expected<'i>
+where
+    T: Deserialize<'i> + core::fmt::Debug,
+{
+    dangerous::input(bytes)
+        .read_all::<_, _, Expected<'_>>(|r| de::deserialize::<T, _>(r, Postcard))
+        .unwrap_err()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Postcard
+
+#[test]
+fn test_postcard_primitives() {
+    assert!(deserialize_ok::<bool>(b"\x01"));
+    assert_eq!(deserialize_ok::<u8>(b"\xff"), 255);
+    assert_eq!(deserialize_ok::<i8>(b"\xff"), -1);
+    assert_eq!(deserialize_ok::<u16>(b"\xff\xff\x03"), u16::MAX);
+    assert_eq!(deserialize_ok::<i32>(b"\x03"), -2);
+    assert_eq!(deserialize_ok::<i64>(b"\x04"), 2);
+    assert_eq!(
+        deserialize_ok::<u128>(
+            b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\x03"
+        ),
+        u128::MAX
+    );
+    assert_eq!(deserialize_ok::<f32>(&1.5f32.to_le_bytes()), 1.5);
+    assert_eq!(
+        deserialize_ok::<char>("\x03\u{2603}".as_bytes()),
+        '\u{2603}'
+    );
+    assert_eq!(deserialize_ok::<&str>(b"\x02hi"), "hi");
+    assert_eq!(deserialize_ok::<&[u8]>(b"\x02hi"), b"hi");
+    assert_eq!(deserialize_ok::<()>(b""), ());
+}
+
+#[test]
+fn test_postcard_compound() {
+    assert_eq!(
+        deserialize_ok::<Header<'_>>(b"\x04test\x01\x01\x03abc"),
+        Header {
+            name: "test",
+            flags: Flags {
+                enabled: true,
+                label: Some("abc"),
+            },
+        }
+    );
+    assert_eq!(deserialize_ok::<Vec<u16>>(b"\x02\x01\x80\x01"), [1, 128]);
+    assert_eq!(deserialize_ok::<(u8, bool)>(b"\x07\x00"), (7, false));
+    let map = deserialize_ok::<BTreeMap<&str, u8>>(b"\x02\x01a\x01\x01b\x02");
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 1), ("b", 2)]);
+}
+
+#[test]
+fn test_postcard_enum() {
+    assert_eq!(deserialize_ok::<Shape>(b"\x00"), Shape::Empty);
+    assert_eq!(deserialize_ok::<Shape>(b"\x01\x80\x01"), Shape::Circle(128));
+    assert_eq!(deserialize_ok::<Shape>(b"\x02\x01\x02"), Shape::Line(-1, 1));
+    assert_eq!(
+        deserialize_ok::<Shape>(b"\x03\x02\x03"),
+        Shape::Rect { w: 2, h: 3 }
+    );
+}
+
+#[test]
+fn test_postcard_invalid() {
+    let error = deserialize_err::<bool>(b"\x02");
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\x02"[..]);
+
+    // Overflows a u16.
+    let error = deserialize_err::<u16>(b"\xff\xff\x04");
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\xff\xff\x04"[..]);
+
+    // More than one char.
+    let error = deserialize_err::<char>(b"\x02ab");
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\x02ab"[..]);
+}
+
+#[test]
+fn test_postcard_truncated() {
+    let error = deserialize_err::<Header<'_>>(b"\x04te");
+    assert!(!error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Rejected values
+
+#[test]
+fn test_unknown_variant() {
+    let error = deserialize_err::<Shape>(b"\x80\x01");
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\x80\x01"[..]);
+}
+
+#[test]
+fn test_deserialize_any() {
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Unit,
+    }
+
+    let error = deserialize_err::<Untagged>(b"\x00");
+    assert!(error.is_fatal());
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Expr {
+    Lit(u8),
+    Neg(Box<Expr>),
+}
+
+#[test]
+fn test_max_depth() {
+    let mut bytes = vec![0x01; 5000];
+    bytes.extend_from_slice(b"\x00\x00");
+    let error = deserialize_err::<Expr>(&bytes);
+    assert!(error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), None);
+
+    let nested = b"\x01\x01\x00\x07";
+    let expr = Expr::Neg(Box::new(Expr::Neg(Box::new(Expr::Lit(7)))));
+    assert_eq!(deserialize_ok::<Expr>(nested), expr);
+    let with_max_depth = |max_depth| {
+        dangerous::input(nested).read_all::<_, _, Expected<'_>>(|r| {
+            de::deserialize_with_max_depth::<Expr, _>(r, Postcard, max_depth)
+        })
+    };
+    assert_eq!(with_max_depth(3).unwrap(), expr);
+    assert!(with_max_depth(2).unwrap_err().is_fatal());
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_field_path_display() {
+    let error = deserialize_err::<Header<'_>>(b"\x04test\x02");
+    assert_eq!(error.span(), b"\x02"[..]);
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to try expect: expected bool
+            > [04 74 65 73 74 02]
+                              ^^ 
+            additional:
+              error offset: 5, input length: 6
+            backtrace:
+              1. `read all`
+              2. `deserialize` (expected test_de::Header<'_>)
+              3. `deserialize struct` (expected Header)
+              4. `deserialize field` (expected flags)
+              5. `deserialize struct` (expected Flags)
+              6. `deserialize field` (expected enabled)
+              7. `try expect` (expected bool)
+        "#}
+    );
+}
@@ -95,3 +95,42 @@ fn test_preferred_format_debug() {
     assert_eq!(format!("{:?}", PreferredFormat::Bytes), "Bytes");
     assert_eq!(format!("{:?}", PreferredFormat::BytesAscii), "BytesAscii");
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// UTF-16
+
+fn utf16(s: &str, endian: Endian) -> Vec<u8> {
+    s.encode_utf16()
+        .flat_map(|unit| match endian {
+            Endian::Big => unit.to_be_bytes(),
+            Endian::Little => unit.to_le_bytes(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_utf16_display() {
+    let le = utf16("hello ♥ 𝄞", Endian::Little);
+    let be = utf16("hello ♥ 𝄞", Endian::Big);
+    let le = input!(le[..]).into_utf16(Endian::Little);
+    let be = input!(be[..]).into_utf16(Endian::Big);
+
+    assert_eq!(le.to_string(), r#""hello ♥ 𝄞""#);
+    assert_eq!(be.to_string(), r#""hello ♥ 𝄞""#);
+    let long = utf16("hello ♥ 𝄞 world, hello ♥ 𝄞 world", Endian::Little);
+    let long = input!(long[..]).into_utf16(Endian::Little);
+    assert_eq!(format!("{:.18}", long), r#""hello" .. "world""#);
+    assert_eq!(format!("{:.24}", long), r#""hello ♥ " .. " 𝄞 world""#);
+    assert_eq!(long.display().head(16).to_string(), r#""hello ♥ 𝄞 w" .."#);
+    assert_eq!(long.display().tail(16).to_string(), r#".. "o ♥ 𝄞 world""#);
+}
+
+#[test]
+fn test_utf16_display_invalid() {
+    // An unpaired high surrogate followed by `a`.
+    let input = input!(b"\x00\xd8a\x00").into_utf16(Endian::Little);
+    assert_eq!(input.to_string(), "[00 d8 'a' 00]");
+    // A trailing odd byte.
+    let input = input!(b"a\x00b").into_utf16(Endian::Little);
+    assert_eq!(input.to_string(), "['a' 00 'b']");
+}
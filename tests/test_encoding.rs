@@ -0,0 +1,112 @@
+#![allow(clippy::result_large_err)]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::encoding::{self, Detected};
+#[cfg(feature = "alloc")]
+use dangerous::encoding::{Charset, Transcoded};
+
+#[test]
+fn test_detect_bom() {
+    assert_eq!(
+        encoding::detect_bom(input!(b"\xEF\xBB\xBFhi")),
+        Detected::Utf8(input!(b"hi"))
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"\xFF\xFEh\x00")),
+        Detected::Utf16(input!(b"h\x00").into_utf16(Endian::Little))
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"\xFE\xFF\x00h")),
+        Detected::Utf16(input!(b"\x00h").into_utf16(Endian::Big))
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"\xFF\xFE\x00\x00h\x00\x00\x00")),
+        Detected::Utf32(input!(b"h\x00\x00\x00"), Endian::Little)
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"\x00\x00\xFE\xFF\x00\x00\x00h")),
+        Detected::Utf32(input!(b"\x00\x00\x00h"), Endian::Big)
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"hi")),
+        Detected::Unknown(input!(b"hi"))
+    );
+    assert_eq!(
+        encoding::detect_bom(input!(b"\xEF\xBB\xBF")).into_bytes(),
+        input!(b"")
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_transcode_charsets() {
+    let input = input!(b"\x80 \x9F \xE9 \x81");
+    assert_eq!(
+        Transcoded::new(input.clone(), Charset::Latin1).as_str(),
+        "\u{80} \u{9F} é \u{81}"
+    );
+    assert_eq!(
+        Transcoded::new(input, Charset::Windows1252).as_str(),
+        "€ Ÿ é \u{81}"
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_transcode_original_range() {
+    let transcoded = Transcoded::new(input!(b"a\x80b\xE9c"), Charset::Windows1252);
+    let text = transcoded.as_str().as_bytes();
+
+    assert_eq!(text, "a€béc".as_bytes());
+    assert_eq!(transcoded.original_range(&text[..1]), Some(0..1));
+    assert_eq!(transcoded.original_range(&text[1..4]), Some(1..2));
+    assert_eq!(transcoded.original_range(&text[4..5]), Some(2..3));
+    assert_eq!(transcoded.original_range(&text[5..]), Some(3..5));
+    assert_eq!(transcoded.original_range(&text[7..]), Some(4..5));
+    // Spans within a char are widened to the original byte.
+    assert_eq!(transcoded.original_range(&text[2..3]), Some(1..2));
+    assert_eq!(transcoded.original_range(&text[..0]), Some(0..0));
+    assert_eq!(transcoded.original_range(&text[8..]), Some(5..5));
+    assert_eq!(transcoded.original_range(b"a"), None);
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_transcoded_error_display() {
+    let transcoded = Transcoded::new(input!(b"name=caf\xE9\nage=\xBD"), Charset::Latin1);
+    let error = transcoded
+        .input()
+        .read_all::<_, _, Expected>(|r| {
+            let name = r.context("name", |r| {
+                r.consume("name=")?;
+                r.take_until('\n')
+            })?;
+            assert_eq!(name, *"café");
+            r.consume("\nage=")?;
+            r.context("age", |r| {
+                r.take_remaining().into_bytes().read_all(|r| r.read_u8())
+            })
+        })
+        .unwrap_err();
+    let error = transcoded.map_error(error);
+
+    // The trailing byte of `½` (`C2 BD`) maps back to the original `BD`.
+    assert_eq!(error.span(), b"\xBD"[..]);
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to read all: found 1 byte when exactly no bytes was expected
+            > [6e 61 6d 65 3d 63 61 66 e9 0a 61 67 65 3d bd]
+                                                         ^^ 
+            additional:
+              error offset: 14, input length: 15
+            backtrace:
+              1. `read all`
+              2. `read` (expected age)
+              3. `read all` (expected no trailing input)
+        "#}
+    );
+}
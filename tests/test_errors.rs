@@ -393,6 +393,119 @@ fn test_expected_value_full() {
     );
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Owned expected
+
+#[cfg(feature = "alloc")]
+fn assert_owned_matches(error: Expected<'_>) {
+    let display = format!("{}", error);
+    let debug = format!("{:?}", error);
+    let str_hint = format!("{:#}", error);
+    let retry_requirement = error.to_retry_requirement();
+    let is_fatal = error.is_fatal();
+    let owned = error.into_owned();
+    assert_eq!(format!("{}", owned), display);
+    assert_eq!(format!("{:?}", owned), debug);
+    assert_eq!(format!("{:#}", owned), str_hint);
+    assert_eq!(owned.to_retry_requirement(), retry_requirement);
+    assert_eq!(owned.is_fatal(), is_fatal);
+    assert_eq!(owned.input_offset(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_owned_expected_display() {
+    assert_owned_matches(trigger_expected_valid());
+    assert_owned_matches(trigger_expected_length());
+    assert_owned_matches(trigger_expected_value());
+    assert_owned_matches(trigger_expected_value_str());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_owned_expected_window() {
+    let buf = [b'a'; 1000];
+    let error = input!(&buf[..])
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(600)?;
+            r.consume(b'b')
+        })
+        .unwrap_err()
+        .into_owned();
+    let details = &error;
+    let input = details.input().into_bytes();
+
+    assert_eq!(error.input_offset(), 344);
+    assert_eq!(input.len(), 513);
+    assert_eq!(details.span().span_of(&input), Some(256..257));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_owned_expected_window_display() {
+    let buf = [b'a'; 1000];
+    let error = input!(&buf[..])
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(600)?;
+            r.consume(b'b')
+        })
+        .unwrap_err();
+    let display = format!("{:#}", error);
+    let owned = error.into_owned();
+
+    assert_eq!(format!("{:#}", owned), display);
+    assert!(display.contains("error offset: 600, input length: 1000"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_owned_expected_window_display_lines() {
+    let buf = "a\n".repeat(500);
+    let error = input!(buf.as_str())
+        .read_all::<_, _, Expected>(|r| {
+            r.skip(600)?;
+            r.consume('b')
+        })
+        .unwrap_err();
+    let display = format!("{}", error);
+    let owned = error.into_owned();
+
+    assert_eq!(format!("{}", owned), display);
+    assert!(display.contains("error line: 301, error offset: 600, input length: 1000"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_owned_expected_window_char_boundary() {
+    let buf = "€".repeat(200);
+    let error = input!(buf.as_str())
+        .read_all::<_, _, Expected>(|r| {
+            r.skip_while(|c| c == '€');
+            r.consume('b')
+        })
+        .unwrap_err()
+        .into_owned();
+
+    assert_eq!(error.input_offset(), 345);
+    assert!((&error).input().is_string());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_owned_expected_send_sync() {
+    fn assert_static_error<E: std::error::Error + Send + Sync + 'static>(_: &E) {}
+
+    let buf = b"hello".to_vec();
+    let error = input!(&buf[..])
+        .read_all::<_, _, Expected>(|r| r.consume(b"world"))
+        .unwrap_err()
+        .into_owned();
+    drop(buf);
+    assert_static_error(&error);
+    let boxed: Box<dyn std::error::Error + Send + Sync> = error.into();
+    assert!(boxed.to_string().starts_with("error attempting to consume"));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Other
 
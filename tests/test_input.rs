@@ -26,6 +26,15 @@ fn test_to_dangerous_non_empty() {
         .unwrap_err();
 }
 
+#[test]
+fn test_ct_eq() {
+    assert!(input!(b"").ct_eq(b""));
+    assert!(input!(b"hello").ct_eq(b"hello"));
+    assert!(!input!(b"hello").ct_eq(b"hellO"));
+    assert!(!input!(b"hello").ct_eq(b"hell"));
+    assert!(!input!(b"hell").ct_eq(b"hello"));
+}
+
 #[test]
 fn test_as_dangerous_str() {
     // Valid
@@ -184,3 +193,269 @@ fn test_span_of_non_empty() {
     let non_span = dangerous::input(&[]);
     assert_eq!(non_span.span_of_non_empty(&parent), None);
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// Input::split
+
+#[test]
+fn test_split() {
+    let parent = input!(b"a,bc,,d");
+    let items: Vec<_> = parent.clone().split(b',').collect();
+    assert_eq!(items, [&b"a"[..], b"bc", b"", b"d"]);
+    assert_eq!(items[1].span_of(&parent), Some(2..4));
+    assert!(items[..3].iter().all(|item| item.is_bound()));
+    assert!(!items[3].is_bound());
+    assert!(input!(b"a,b")
+        .into_bound()
+        .split(b',')
+        .all(|item| item.is_bound()));
+}
+
+#[test]
+fn test_split_trailing() {
+    let items: Vec<_> = input!(b"a,").split(b',').collect();
+    assert_eq!(items, [&b"a"[..], b""]);
+}
+
+#[test]
+fn test_split_empty_match() {
+    let items: Vec<_> = input!(b"ab").split(pattern::seq(b"")).collect();
+    assert_eq!(items, [&b"ab"[..]]);
+}
+
+#[test]
+fn test_splitn() {
+    let items: Vec<_> = input!("a=b=c").splitn(2, '=').collect();
+    assert_eq!(items, ["a", "b=c"]);
+    assert_eq!(input!("a=b").splitn(0, '=').count(), 0);
+    assert_eq!(input!("a=b").splitn(5, '=').count(), 2);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Bytes::lines / String::lines
+
+#[test]
+fn test_bytes_lines() {
+    let parent = input!(b"one\r\ntwo\n\nthree\r");
+    let lines: Vec<_> = parent.clone().lines().collect();
+    assert_eq!(lines, [&b"one"[..], b"two", b"", b"three\r"]);
+    assert_eq!(lines[1].span_of(&parent), Some(5..8));
+    assert!(lines[..3].iter().all(|line| line.is_bound()));
+    assert!(!lines[3].is_bound());
+}
+
+#[test]
+fn test_bytes_lines_trailing_newline() {
+    let lines: Vec<_> = input!(b"one\ntwo\n").lines().collect();
+    assert_eq!(lines, [&b"one"[..], b"two"]);
+    assert!(lines.iter().all(|line| line.is_bound()));
+    assert_eq!(input!(b"").lines().count(), 0);
+}
+
+#[test]
+fn test_string_lines() {
+    let lines: Vec<_> = input!("héllo\r\nwörld").lines().collect();
+    assert_eq!(lines, ["héllo", "wörld"]);
+}
+
+#[test]
+fn test_lines_error_display() {
+    let parent = input!(b"ok\nbad\n");
+    let line = parent.clone().lines().nth(1).unwrap();
+    let error = line
+        .read_all::<_, _, Expected>(|r| r.consume(b"ok"))
+        .unwrap_err();
+    assert_eq!(error.input().into_bytes().span_of(&parent), Some(3..6));
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SharedBytes
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_shared_bytes_slice_ref() {
+    let shared = SharedBytes::from(b"hello world".to_vec());
+    let world = dangerous::input(&shared)
+        .read_all::<_, _, Expected>(|r| {
+            r.consume(b"hello ")?;
+            Ok(r.take_remaining())
+        })
+        .unwrap();
+    let world = shared.slice_ref(&world).unwrap();
+    assert_eq!(world, b"world"[..]);
+    assert_eq!(world.input().span_of(&world.input()), Some(0..5));
+    assert!(shared.slice_ref(&input!(b"world")).is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_shared_bytes_slice() {
+    let shared = SharedBytes::from(&b"hello"[..]);
+    assert!(!shared.input().is_bound());
+    let ell = shared.slice(1..4).unwrap();
+    assert_eq!(ell, b"ell"[..]);
+    assert!(ell.input().is_bound());
+    assert_eq!(ell.slice(1..3).unwrap(), b"ll"[..]);
+    assert!(ell.slice(2..4).is_none());
+    assert_eq!(
+        format!("{:?}", ell),
+        "SharedBytes { bound: Both, value: [65 6c 6c], .. }"
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_shared_bytes_send() {
+    let shared = SharedBytes::from(b"hello world".to_vec());
+    let hello = shared.slice(0..5).unwrap();
+    drop(shared);
+    let handle = std::thread::spawn(move || hello.input().to_dangerous_str::<Expected>().is_ok());
+    assert!(handle.join().unwrap());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// bytes::Bytes / Bytes::slice_ref_of
+
+#[test]
+#[cfg(feature = "bytes")]
+fn test_bytes_crate_into_input() {
+    let buf = bytes::Bytes::from_static(b"hello");
+    assert_eq!(dangerous::input(&buf), b"hello"[..]);
+    assert!(!dangerous::input(&buf).is_bound());
+    let buf_mut = bytes::BytesMut::from(&b"hello"[..]);
+    assert_eq!(dangerous::input(&buf_mut), b"hello"[..]);
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn test_bytes_slice_ref_of() {
+    let buf = bytes::Bytes::from(b"hello world".to_vec());
+    let (hello, world) = dangerous::input(&buf)
+        .read_all::<_, _, Expected>(|r| Ok((r.take_until_consume(b' ')?, r.take_remaining())))
+        .unwrap();
+    assert_eq!(hello, b"hello"[..]);
+    let world = world.slice_ref_of(&buf).unwrap();
+    drop(buf);
+    assert_eq!(world, "world");
+
+    let buf = bytes::Bytes::from(b"hello".to_vec());
+    let end = input!(&buf[5..]).slice_ref_of(&buf).unwrap();
+    assert!(end.is_empty());
+    assert_eq!(input!(b"hello").slice_ref_of(&buf), None);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// String::to_dangerous_printable_str
+
+#[test]
+fn test_to_dangerous_printable_str() {
+    let none = AllowedControls::none();
+    assert_eq!(
+        input!("hello world")
+            .to_dangerous_printable_str::<Expected>(none)
+            .unwrap(),
+        "hello world"
+    );
+    for (s, span) in &[
+        ("a\0b", "\0"),
+        ("a\tb", "\t"),
+        ("a\r\nb", "\r"),
+        ("a\x7fb", "\x7f"),
+        ("a\u{85}b", "\u{85}"),
+        ("a\u{202e}b", "\u{202e}"),
+        ("a\u{2066}b", "\u{2066}"),
+    ] {
+        let error = input!(*s)
+            .to_dangerous_printable_str::<Expected>(none)
+            .unwrap_err();
+        assert!(error.is_fatal());
+        assert_eq!(error.span(), span.as_bytes());
+    }
+}
+
+#[test]
+fn test_to_dangerous_printable_str_allowed() {
+    let allowed = AllowedControls::none().tab(true).newline(true);
+    assert_eq!(
+        input!("a\tb\r\n")
+            .to_dangerous_printable_str::<Expected>(allowed)
+            .unwrap(),
+        "a\tb\r\n"
+    );
+    let error = input!("a\t\u{202e}\n")
+        .to_dangerous_printable_str::<Expected>(allowed)
+        .unwrap_err();
+    assert_eq!(error.span(), "\u{202e}".as_bytes());
+    let error = input!("a\n")
+        .to_dangerous_printable_str::<Expected>(AllowedControls::none().tab(true))
+        .unwrap_err();
+    assert_eq!(error.span(), b"\n"[..]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// String::is_nfc / String::to_dangerous_nfc_str
+
+#[test]
+#[cfg(feature = "unicode-normalization")]
+fn test_is_nfc() {
+    assert!(input!("").is_nfc());
+    assert!(input!("caf\u{e9}").is_nfc());
+    assert!(!input!("cafe\u{301}").is_nfc());
+    // ANGSTROM SIGN decomposes to a different char.
+    assert!(!input!("\u{212b}").is_nfc());
+}
+
+#[test]
+#[cfg(feature = "unicode-normalization")]
+fn test_to_dangerous_nfc_str() {
+    assert_eq!(
+        input!("caf\u{e9}")
+            .to_dangerous_nfc_str::<Expected>()
+            .unwrap(),
+        "caf\u{e9}"
+    );
+    let error = input!("cafe\u{301}\u{323}!")
+        .to_dangerous_nfc_str::<Expected>()
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), "e\u{301}\u{323}".as_bytes());
+
+    let error = input!("1\u{212b}2")
+        .to_dangerous_nfc_str::<Expected>()
+        .unwrap_err();
+    assert_eq!(error.span(), "\u{212b}".as_bytes());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// String::is_confusable_with / String::to_dangerous_single_script_str
+
+#[test]
+#[cfg(feature = "unicode-security")]
+fn test_is_confusable_with() {
+    assert!(input!("paypal").is_confusable_with("paypal"));
+    assert!(input!("p\u{430}yp\u{430}l").is_confusable_with("paypal"));
+    assert!(input!("rn").is_confusable_with("m"));
+    assert!(input!("paypal").is_confusable_with("paypa1"));
+    assert!(!input!("paypal").is_confusable_with("paypals"));
+}
+
+#[test]
+#[cfg(feature = "unicode-security")]
+fn test_to_dangerous_single_script_str() {
+    assert!(input!("user_01").is_single_script());
+    assert!(input!("\u{43f}\u{440}\u{438}\u{432}\u{435}\u{442}").is_single_script());
+    assert!(!input!("p\u{430}ypal").is_single_script());
+    assert_eq!(
+        input!("\u{65e5}\u{672c}\u{8a9e}\u{304b}\u{306a}")
+            .to_dangerous_single_script_str::<Expected>()
+            .unwrap(),
+        "\u{65e5}\u{672c}\u{8a9e}\u{304b}\u{306a}"
+    );
+
+    let error = input!("pay\u{440}al")
+        .to_dangerous_single_script_str::<Expected>()
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), "\u{440}".as_bytes());
+}
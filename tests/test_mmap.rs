@@ -0,0 +1,135 @@
+#![allow(clippy::result_large_err)]
+#![cfg(feature = "memmap")]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::mmap::MappedFile;
+use std::fs;
+use std::path::PathBuf;
+
+/// A file in the temp directory removed when dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, contents: &[u8]) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "dangerous-test-mmap-{}-{}",
+            std::process::id(),
+            name
+        ));
+        fs::write(&path, contents).unwrap();
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+    let len = r.read_u8()?;
+    Ok(r.take(len as usize)?.as_dangerous().to_vec())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MappedFile
+
+#[test]
+fn test_mapped_file_input() {
+    let tmp = TempFile::new("input", b"hello");
+    let file = unsafe { MappedFile::open(&tmp.0) }.unwrap();
+    assert_eq!(file.len(), 5);
+    assert_eq!(file.as_dangerous(), b"hello");
+    assert!(file.input().is_bound());
+    assert_eq!(dangerous::input(&file), b"hello"[..]);
+    assert_eq!(format!("{:?}", file), "MappedFile { len: 5, .. }");
+}
+
+#[test]
+fn test_mapped_file_input_bound() {
+    let tmp = TempFile::new("bound", b"hello");
+    let file = unsafe { MappedFile::open(&tmp.0) }.unwrap();
+    let error = file
+        .input()
+        .read_all::<_, _, Expected<'_>>(|r| r.take(6))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MappedDecoder
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_mapped_decoder_windows() {
+    use dangerous::mmap::MappedDecoder;
+
+    let mut contents = Vec::new();
+    let mut expected = Vec::new();
+    for len in 0..20u8 {
+        let message: Vec<u8> = (0..len).collect();
+        contents.push(len);
+        contents.extend_from_slice(&message);
+        expected.push(message);
+    }
+    let tmp = TempFile::new("windows", &contents);
+    for window_len in &[1, 3, 16, 4096] {
+        let mut decoder = unsafe { MappedDecoder::open(&tmp.0, *window_len) }.unwrap();
+        let messages: Vec<_> = decoder
+            .messages(decode_message)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(messages, expected);
+        assert_eq!(decoder.offset(), contents.len() as u64);
+        assert!(decoder.decode(decode_message).unwrap().is_none());
+    }
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_mapped_decoder_messages_consuming_nothing() {
+    use dangerous::mmap::MappedDecoder;
+
+    let tmp = TempFile::new("consuming-nothing", b"hi");
+    let mut decoder = unsafe { MappedDecoder::open(&tmp.0, 8) }.unwrap();
+    let messages: Vec<_> = decoder
+        .messages(|r| r.peek_u8())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(messages, [b'h']);
+    assert_eq!(decoder.offset(), 0);
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_mapped_decoder_empty() {
+    use dangerous::mmap::MappedDecoder;
+
+    let tmp = TempFile::new("empty", b"");
+    let mut decoder = unsafe { MappedDecoder::open(&tmp.0, 8) }.unwrap();
+    assert!(decoder.decode(decode_message).unwrap().is_none());
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_mapped_decoder_truncated() {
+    use dangerous::mmap::MappedDecoder;
+    use dangerous::stream::StreamError;
+
+    let tmp = TempFile::new("truncated", &[2, b'h', b'i', 3, b'y', b'o']);
+    let mut decoder = unsafe { MappedDecoder::open(&tmp.0, 2) }.unwrap();
+    assert_eq!(
+        decoder.decode(decode_message).unwrap(),
+        Some(b"hi".to_vec())
+    );
+    match decoder.decode(decode_message) {
+        Err(StreamError::Invalid(err)) => assert!(err.is_fatal()),
+        other => panic!("expected invalid error, got {:?}", other),
+    }
+    assert_eq!(decoder.offset(), 3);
+}
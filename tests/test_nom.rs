@@ -0,0 +1,111 @@
+#![allow(clippy::result_large_err)]
+#![cfg(feature = "nom")]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use nom::bytes::{complete, streaming};
+use nom::character::is_digit;
+use nom::sequence::preceded;
+
+type NomError<I> = nom::error::Error<I>;
+
+///////////////////////////////////////////////////////////////////////////////
+// BytesReader::read_nom
+
+#[test]
+fn test_read_nom() {
+    let (method, rest) = read_all_ok!(b"GET 123", |r| {
+        let method = r.read_nom("method", complete::tag(b"GET"))?;
+        Ok((method, r.take_remaining()))
+    });
+    assert_eq!(method, b"GET");
+    assert_eq!(rest, b" 123"[..]);
+}
+
+#[test]
+fn test_read_nom_error_span() {
+    let error = read_all_err!(b"GET x1", |r| {
+        r.read_nom(
+            "request",
+            preceded(complete::tag(b"GET "), complete::take_while1(is_digit)),
+        )
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"x1"[..]);
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_read_nom_incomplete() {
+    let error = read_all_err!(b"GE", |r| r.read_nom("method", streaming::tag(b"GET")));
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+    assert_eq!(error.span(), b"GE"[..]);
+
+    let error = dangerous::input(b"GE")
+        .into_bound()
+        .read_all::<_, _, Expected<'_>>(|r| r.read_nom("method", streaming::tag(b"GET")))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_read_nom_error_display() {
+    let error = read_all_err!(b"GET x1", |r| {
+        r.read_nom(
+            "request",
+            preceded(complete::tag(b"GET "), complete::take_while1(is_digit)),
+        )
+    });
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to read nom: expected request
+            > [47 45 54 20 78 31]
+                           ^^ ^^ 
+            additional:
+              error offset: 4, input length: 6
+            backtrace:
+              1. `read all`
+              2. `read nom` (expected request)
+        "#}
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Bytes as nom input
+
+#[test]
+fn test_bytes_nom_input() {
+    let input = dangerous::input(b"GET 123");
+    let (rest, method) = complete::tag::<_, _, NomError<_>>("GET ")(input).unwrap();
+    assert_eq!(method, b"GET "[..]);
+    assert_eq!(rest, b"123"[..]);
+    let (rest, digits) = complete::take_while1::<_, _, NomError<_>>(is_digit)(rest).unwrap();
+    assert_eq!(digits, b"123"[..]);
+    assert!(rest.is_empty());
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_bytes_nom_input_bound() {
+    let digits = streaming::take_while1::<_, Bytes<'_>, NomError<_>>(is_digit);
+    assert!(matches!(
+        digits(dangerous::input(b"123")),
+        Err(nom::Err::Incomplete(_))
+    ));
+    let (rest, value) = digits(dangerous::input(b"123").into_bound()).unwrap();
+    assert_eq!(value, b"123"[..]);
+    assert!(rest.is_empty());
+    assert!(value.is_bound());
+
+    let value = read_all_ok!(b"123", |r| {
+        r.take_remaining()
+            .into_bound()
+            .read_all(|r| r.read_nom_bytes("digits", &digits))
+    });
+    assert_eq!(value, b"123"[..]);
+}
@@ -360,3 +360,349 @@ fn test_match_string_regex_none() {
         "!!!!"[..]
     )
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// match: any_of
+
+#[test]
+fn test_match_any_of_bytes() {
+    for set in [&b"="[..], b"=;", b";=,", b"\t;,="].iter() {
+        assert_eq!(
+            read_all_ok!(b"key=value", |r| {
+                let v = r.take_until_opt(pattern::any_of(set));
+                r.skip(6)?;
+                Ok(v)
+            }),
+            b"key"[..]
+        );
+    }
+}
+
+#[test]
+fn test_match_any_of_bytes_none() {
+    for set in [&b"\r\n\t "[..], b""].iter() {
+        assert_eq!(
+            read_all_ok!(b"hello", |r| {
+                let (v, consumed) = r.take_until_consume_opt(pattern::any_of(set));
+                assert!(!consumed);
+                Ok(v)
+            }),
+            b"hello"[..]
+        );
+    }
+}
+
+#[test]
+fn test_reject_any_of_bytes() {
+    assert_eq!(
+        read_all_ok!(b" \t\r\nhello", |r| {
+            r.skip_while(pattern::any_of(b"\r\n\t "));
+            Ok(r.take_remaining())
+        }),
+        b"hello"[..]
+    );
+}
+
+#[test]
+fn test_match_any_of_string() {
+    assert_eq!(
+        read_all_ok!("héllo wörld", |r| {
+            let v = r.take_until_opt(pattern::any_of(b"\t "));
+            r.skip(6)?;
+            Ok(v)
+        }),
+        "héllo"[..]
+    );
+}
+
+#[test]
+fn test_any_of_string_ignores_non_ascii() {
+    // 0xa9 is the second byte of 'é' and must never match.
+    assert_eq!(
+        read_all_ok!("é!", |r| {
+            let v = r.take_until_opt(pattern::any_of(b"\xa9!"));
+            r.skip(1)?;
+            Ok(v)
+        }),
+        "é"[..]
+    );
+    assert_eq!(
+        read_all_ok!("!!é", |r| {
+            let v = r.take_while(pattern::any_of(b"\xa9!"));
+            r.skip(1)?;
+            Ok(v)
+        }),
+        "!!"[..]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// range
+
+#[test]
+fn test_range_bytes() {
+    assert_eq!(
+        read_all_ok!(b"123abc", |r| {
+            let v = r.take_while(pattern::range(b'0'..=b'9'));
+            r.skip(3)?;
+            Ok(v)
+        }),
+        b"123"[..]
+    );
+    assert_eq!(
+        read_all_ok!(b"abc123", |r| {
+            let v = r.take_until_opt(pattern::range(b'0'..=b'9'));
+            r.skip(3)?;
+            Ok(v)
+        }),
+        b"abc"[..]
+    );
+}
+
+#[test]
+fn test_range_string() {
+    assert_eq!(
+        read_all_ok!("123é", |r| {
+            let v = r.take_while(pattern::range(0..=0x7f));
+            r.skip(1)?;
+            Ok(v)
+        }),
+        "123"[..]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// seq
+
+#[test]
+fn test_match_seq_bytes() {
+    assert_eq!(
+        read_all_ok!(b"a\rb\r\nc", |r| {
+            let v = r.take_until_consume(pattern::seq(b"\r\n"))?;
+            r.consume(b'c')?;
+            Ok(v)
+        }),
+        b"a\rb"[..]
+    );
+    let _ = read_all_err!(b"a\rb\n", |r| { r.take_until(pattern::seq(b"\r\n")) });
+}
+
+#[test]
+fn test_reject_seq_bytes() {
+    assert_eq!(
+        read_all_ok!(b"ababa", |r| {
+            let v = r.take_while(pattern::seq(b"ab"));
+            r.consume(b'a')?;
+            Ok(v)
+        }),
+        b"abab"[..]
+    );
+}
+
+#[test]
+fn test_seq_string() {
+    assert_eq!(
+        read_all_ok!("héllo", |r| {
+            let v = r.take_until_consume(pattern::seq("é".as_bytes()))?;
+            r.consume("llo")?;
+            Ok(v)
+        }),
+        "h"[..]
+    );
+    // An invalid UTF-8 sequence never matches string input.
+    let _ = read_all_err!("é", |r| { r.take_until(pattern::seq(b"\xa9")) });
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ascii_ci
+
+#[test]
+fn test_match_ascii_ci_bytes() {
+    assert_eq!(
+        read_all_ok!(b"body--BOUNDARY--", |r| {
+            let v = r.take_until_consume(pattern::ascii_ci("--boundary"))?;
+            r.consume(b"--")?;
+            Ok(v)
+        }),
+        b"body"[..]
+    );
+}
+
+#[test]
+fn test_match_ascii_ci_bytes_none() {
+    let error = read_all_err!(b"body--bound", |r| {
+        r.take_until(pattern::ascii_ci("--Boundary"))
+    });
+    assert_eq!(
+        error.expected().unwrap().display().to_string(),
+        "\"--Boundary\""
+    );
+}
+
+#[test]
+fn test_reject_ascii_ci_bytes() {
+    assert_eq!(
+        read_all_ok!(b"aBAbA", |r| {
+            let v = r.take_while(pattern::ascii_ci("ab"));
+            r.consume(b'A')?;
+            Ok(v)
+        }),
+        b"aBAb"[..]
+    );
+}
+
+#[test]
+fn test_match_ascii_ci_string() {
+    assert_eq!(
+        read_all_ok!("xéYé", |r| {
+            let v = r.take_until_consume(pattern::ascii_ci("yé"))?;
+            Ok(v)
+        }),
+        "xé"[..]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// not
+
+#[test]
+fn test_not_bytes() {
+    assert_eq!(
+        read_all_ok!(b"   hello", |r| {
+            let v = r.take_until_opt(pattern::not(b' '));
+            r.consume(b"hello")?;
+            Ok(v)
+        }),
+        b"   "[..]
+    );
+}
+
+#[test]
+fn test_not_string() {
+    assert_eq!(
+        read_all_ok!("ééa", |r| {
+            let v = r.take_while(pattern::not('a'));
+            r.consume('a')?;
+            Ok(v)
+        }),
+        "éé"[..]
+    );
+    assert_eq!(
+        read_all_ok!("aaé!", |r| {
+            let (v, consumed) = r.take_until_consume_opt(pattern::not('a'));
+            assert!(consumed);
+            r.consume('!')?;
+            Ok(v)
+        }),
+        "aa"[..]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// or
+
+#[test]
+fn test_or_bytes() {
+    assert_eq!(
+        read_all_ok!(b"ab-cd_ef", |r| {
+            let v = r.take_while(pattern::or(pattern::range(b'a'..=b'z'), b'-'));
+            r.skip(3)?;
+            Ok(v)
+        }),
+        b"ab-cd"[..]
+    );
+    assert_eq!(
+        read_all_ok!(b"abc\r\n", |r| {
+            let v = r.take_until_opt(pattern::or(b'\n', pattern::seq(b"\r\n")));
+            r.skip(2)?;
+            Ok(v)
+        }),
+        b"abc"[..]
+    );
+}
+
+#[test]
+fn test_or_string() {
+    assert_eq!(
+        read_all_ok!("é1é2!", |r| {
+            let v = r.take_while(pattern::or('é', pattern::range(b'0'..=b'9')));
+            r.consume('!')?;
+            Ok(v)
+        }),
+        "é1é2"[..]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// any_literal
+
+#[test]
+#[cfg(feature = "aho-corasick")]
+fn test_match_any_literal_bytes() {
+    let literals = pattern::any_literal(["\r\n", "\n", "\0"]).unwrap();
+    assert_eq!(
+        read_all_ok!(b"a\nb\r\nc\0", |r| {
+            let a = r.take_until_consume_literal(&literals)?;
+            let b = r.take_until_consume_literal(&literals)?;
+            let c = r.take_until_consume_literal(&literals)?;
+            Ok((a, b, c))
+        }),
+        ((input!(b"a"), 1), (input!(b"b"), 0), (input!(b"c"), 2))
+    );
+}
+
+#[test]
+#[cfg(feature = "aho-corasick")]
+fn test_match_any_literal_bytes_none() {
+    let literals = pattern::any_literal(["\r\n", "\n"]).unwrap();
+    let error = read_all_err!(b"hello", |r| { r.take_until_literal(&literals) });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+    let error = input!(b"hello")
+        .into_bound()
+        .read_all::<_, _, Expected>(|r| r.take_until_literal(&literals))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+#[cfg(feature = "aho-corasick")]
+fn test_match_any_literal_pattern() {
+    let literals = pattern::any_literal(["--", "\r\n"]).unwrap();
+    assert_eq!(
+        read_all_ok!(b"ab\r\n--", |r| {
+            let v = r.take_until_opt(&literals);
+            r.skip(4)?;
+            Ok(v)
+        }),
+        b"ab"[..]
+    );
+}
+
+#[test]
+#[cfg(feature = "aho-corasick")]
+fn test_reject_any_literal() {
+    let literals = pattern::any_literal(["ab", "c"]).unwrap();
+    assert_eq!(
+        read_all_ok!(b"abcabd", |r| {
+            let v = r.take_while(&literals);
+            r.consume(b'd')?;
+            Ok(v)
+        }),
+        b"abcab"[..]
+    );
+}
+
+#[test]
+#[cfg(feature = "aho-corasick")]
+fn test_any_literal_string_char_boundary() {
+    // "\xa9" is the second byte of 'é' and must never match.
+    let literals = pattern::any_literal([&b"\xa9"[..], b"!"]).unwrap();
+    assert_eq!(
+        read_all_ok!("é!", |r| {
+            let v = r.take_until_literal(&literals)?;
+            r.consume('!')?;
+            Ok(v)
+        }),
+        (input!("é"), 1)
+    );
+}
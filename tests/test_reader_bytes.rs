@@ -76,6 +76,126 @@ fn test_consume_opt_u8_false() {
     }));
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_ignore_ascii_case
+
+#[test]
+fn test_consume_ignore_ascii_case() {
+    read_all_ok!(b"Content-LENGTH", |r| {
+        r.consume_ignore_ascii_case(b"content-length")
+    });
+    read_all_ok!(b"A", |r| { r.consume_ignore_ascii_case(b'a') });
+}
+
+#[test]
+fn test_consume_ignore_ascii_case_different_value() {
+    let error = read_all_err!(b"content-type", |r| {
+        r.consume_ignore_ascii_case("Content-Length")
+    });
+    assert_eq!(error.to_retry_requirement(), None);
+    assert_eq!(error.span(), b"content-type"[..]);
+    assert_eq!(error.expected().unwrap().as_bytes(), b"Content-Length");
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_consume_ignore_ascii_case_different_value_display() {
+    let error = read_all_err!(b"content-type", |r| {
+        r.consume_ignore_ascii_case("Content-Length")
+    });
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to consume ignoring ASCII case: found a different value to the exact expected
+            expected:
+            > [43 6f 6e 74 65 6e 74 2d 4c 65 6e 67 74 68]
+            in:
+            > [63 6f 6e 74 65 6e 74 2d 74 79 70 65]
+               ^^ ^^ ^^ ^^ ^^ ^^ ^^ ^^ ^^ ^^ ^^ ^^ 
+            additional:
+              error offset: 0, input length: 12
+            backtrace:
+              1. `read all`
+              2. `consume ignoring ASCII case` (expected exact value ignoring ASCII case)
+        "#}
+    );
+}
+
+#[test]
+fn test_consume_ignore_ascii_case_not_enough_input() {
+    assert_eq!(
+        read_all_err!(b"CONTENT", |r| {
+            r.consume_ignore_ascii_case(b"content-length")
+        })
+        .to_retry_requirement(),
+        RetryRequirement::new(7)
+    );
+}
+
+#[test]
+fn test_peek_eq_ignore_ascii_case() {
+    assert!(read_all_ok!(b"HeLLo", |r| {
+        let v = r.peek_eq_ignore_ascii_case(b"hello");
+        r.skip(5)?;
+        Ok(v)
+    }));
+    assert!(!read_all_ok!(b"help!", |r| {
+        let v = r.peek_eq_ignore_ascii_case(b"hello");
+        r.skip(5)?;
+        Ok(v)
+    }));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_ct
+
+#[test]
+fn test_consume_ct_exact_same() {
+    read_all_ok!(b"secret", |r| { r.consume_ct(b"secret") });
+}
+
+#[test]
+fn test_consume_ct_different_value() {
+    let error = read_all_err!(b"secret!", |r| {
+        r.consume_ct(b"seCret")?;
+        r.consume(b'!')
+    });
+    assert!(error.is_fatal());
+    // The whole secret length is spanned and the secret is not reported.
+    assert_eq!(error.span(), b"secret"[..]);
+    assert!(error.expected().is_none());
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_consume_ct_different_value_display() {
+    let error = read_all_err!(b"secret!", |r| {
+        r.consume_ct(b"seCret")?;
+        r.consume(b'!')
+    });
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to consume secret: expected matching secret
+            > [73 65 63 72 65 74 21]
+               ^^ ^^ ^^ ^^ ^^ ^^    
+            additional:
+              error offset: 0, input length: 7
+            backtrace:
+              1. `read all`
+              2. `consume secret` (expected matching secret)
+        "#}
+    );
+}
+
+#[test]
+fn test_consume_ct_not_enough_input() {
+    assert_eq!(
+        read_all_err!(b"sec", |r| { r.consume_ct(b"secret") }).to_retry_requirement(),
+        RetryRequirement::new(3)
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek_u8
 
@@ -204,6 +324,18 @@ macro_rules! test_can_read_num {
                         read_all_ok!(<$ty>::to_be_bytes(<$ty>::MAX), |r| r.[<read_ $ty _be>]()),
                         <$ty>::MAX
                     );
+                    assert_eq!(
+                        read_all_ok!(<$ty>::to_le_bytes(<$ty>::MAX), |r| {
+                            r.with_endian(Endian::Little, |r| r.[<read_ $ty>]())
+                        }),
+                        <$ty>::MAX
+                    );
+                    assert_eq!(
+                        read_all_ok!(<$ty>::to_be_bytes(<$ty>::MAX), |r| {
+                            r.with_endian(Endian::Big, |r| r.[<read_ $ty>]())
+                        }),
+                        <$ty>::MAX
+                    );
                 }
             }
         )*
@@ -224,3 +356,251 @@ fn test_can_read_i8() {
 }
 
 test_can_read_num!(u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::with_endian
+
+#[test]
+fn test_endian_default_big() {
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02], |r| {
+            assert_eq!(r.endian(), Endian::Big);
+            r.read_u16()
+        }),
+        0x0102
+    );
+}
+
+#[test]
+fn test_with_endian_restores_outer() {
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x01, 0x02], |r| {
+            let inner = r.with_endian(Endian::Little, |r| r.read_u16())?;
+            Ok((inner, r.read_u16()?))
+        }),
+        (0x0201, 0x0102)
+    );
+}
+
+#[test]
+fn test_with_endian_inherited_by_sub_reader() {
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02], |r| {
+            r.with_endian(Endian::Little, |r| {
+                r.try_expect("value", |r| r.read_u16().map(Some))
+            })
+        }),
+        0x0201
+    );
+}
+
+#[test]
+fn test_with_endian_runtime_selected() {
+    fn read_header<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<u32, Expected<'i>> {
+        let endian = if r.consume_opt(b"II") {
+            Endian::Little
+        } else {
+            r.consume(b"MM")?;
+            Endian::Big
+        };
+        r.with_endian(endian, |r| r.read_u32())
+    }
+    assert_eq!(read_all_ok!(b"II\x2a\x00\x00\x00", read_header), 42);
+    assert_eq!(read_all_ok!(b"MM\x00\x00\x00\x2a", read_header), 42);
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_with_endian_error_context() {
+    let error = read_all_err!(&[0x01, 0x02], |r| {
+        r.with_endian(Endian::Little, |r| r.read_u32())
+    });
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to read little-endian u32: found 2 bytes when at least 4 bytes was expected
+            > [01 02]
+               ^^ ^^ 
+            additional:
+              error offset: 0, input length: 2
+            backtrace:
+              1. `read all`
+              2. `read little-endian`
+              3. `read little-endian u32` (expected enough input)
+        "#}
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test odd width numbers
+
+#[test]
+fn test_can_read_u24() {
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| r.read_u24_be()),
+        0x01_02_03
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| r.read_u24_le()),
+        0x03_02_01
+    );
+    assert_eq!(
+        read_all_ok!(&[0xff, 0xff, 0xff], |r| r.read_u24_be()),
+        0xff_ff_ff
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| {
+            r.with_endian(Endian::Little, |r| r.read_u24())
+        }),
+        0x03_02_01
+    );
+}
+
+#[test]
+fn test_can_read_i24() {
+    assert_eq!(read_all_ok!(&[0x00, 0x00, 0x01], |r| r.read_i24_be()), 1);
+    assert_eq!(read_all_ok!(&[0xff, 0xff, 0xff], |r| r.read_i24_be()), -1);
+    assert_eq!(
+        read_all_ok!(&[0x00, 0x00, 0x80], |r| r.read_i24_le()),
+        -8_388_608
+    );
+    assert_eq!(
+        read_all_ok!(&[0xff, 0xff, 0x7f], |r| r.read_i24_le()),
+        8_388_607
+    );
+}
+
+#[test]
+fn test_can_read_u48() {
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06], |r| r.read_u48_be()),
+        0x01_02_03_04_05_06
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06], |r| r.read_u48_le()),
+        0x06_05_04_03_02_01
+    );
+}
+
+#[test]
+fn test_read_u24_retry() {
+    let error = read_all_err!(&[0x01, 0x02], |r| r.read_u24_be());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+    assert_eq!(
+        error.context_stack().root().operation(),
+        "read big-endian u24"
+    );
+}
+
+#[test]
+fn test_can_read_uint() {
+    assert_eq!(read_all_ok!(&[], |r| r.read_uint_be(0)), 0);
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| r.read_uint_be(3)),
+        0x01_02_03
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| r.read_uint_le(3)),
+        0x03_02_01
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0x02, 0x03], |r| {
+            r.with_endian(Endian::Little, |r| r.read_uint(3))
+        }),
+        0x03_02_01
+    );
+    assert_eq!(
+        read_all_ok!(u64::MAX.to_be_bytes(), |r| r.read_uint_be(8)),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_read_uint_wider_than_u64() {
+    assert_eq!(
+        read_all_ok!(&[0x00, 0x00, 0, 0, 0, 0, 0, 0, 0, 0x01], |r| r
+            .read_uint_be(10)),
+        1
+    );
+    assert_eq!(
+        read_all_ok!(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x00], |r| r
+            .read_uint_le(10)),
+        1
+    );
+    let error = read_all_err!(&[0x01, 0x00, 0, 0, 0, 0, 0, 0, 0, 0x01], |r| {
+        r.read_uint_be(10)
+    });
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_read_uint_retry() {
+    let error = read_all_err!(&[0x01], |r| r.read_uint_be(3));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test plain-old-data
+
+#[cfg(feature = "bytemuck")]
+#[repr(C, align(4))]
+struct Aligned([u8; 9]);
+
+#[cfg(feature = "bytemuck")]
+const ALIGNED: Aligned = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 3]);
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_read_ref_aligned() {
+    assert_eq!(
+        read_partial_ok!(ALIGNED.0, |r| r.read_ref::<u32>()).0,
+        &u32::from_ne_bytes([1, 0, 0, 0])
+    );
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_read_ref_unaligned() {
+    let error = read_partial_err!(ALIGNED.0, |r| {
+        r.skip(1)?;
+        r.read_ref::<u32>()
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.span().as_dangerous(), &[0, 0, 0, 2]);
+    assert_eq!(error.context_stack().root().operation(), "read ref");
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_read_ref_retry() {
+    let error = read_partial_err!(&[1, 2], |r| r.read_ref::<[u8; 4]>());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_read_slice() {
+    let (values, remaining) = read_partial_ok!(ALIGNED.0, |r| r.read_slice::<u32>(2));
+    assert_eq!(
+        values,
+        &[
+            u32::from_ne_bytes([1, 0, 0, 0]),
+            u32::from_ne_bytes([2, 0, 0, 0])
+        ]
+    );
+    assert_eq!(remaining, b"\x03"[..]);
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn test_read_pod_unaligned() {
+    assert_eq!(
+        read_all_ok!(ALIGNED.0, |r| {
+            r.skip(1)?;
+            let v = r.read_pod::<u32>()?;
+            r.skip(4)?;
+            Ok(v)
+        }),
+        u32::from_ne_bytes([0, 0, 0, 2])
+    );
+}
@@ -76,6 +76,19 @@ fn test_consume_opt_char_false() {
     }));
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_ignore_ascii_case (str)
+
+#[test]
+fn test_consume_ignore_ascii_case_str() {
+    read_all_ok!("ÉtÉ", |r| { r.consume_ignore_ascii_case("ÉTÉ") });
+    // Only ASCII letters are folded, so `é` does not match `É`.
+    let error = read_all_err!("été", |r| { r.consume_ignore_ascii_case("ÉTÉ") });
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), "été".as_bytes());
+    assert_eq!(error.expected().unwrap().as_bytes(), "ÉTÉ".as_bytes());
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek_char
 
@@ -105,3 +118,132 @@ fn test_peek_char_opt() {
         true
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::verify_no_bidi_controls
+
+#[test]
+fn test_verify_no_bidi_controls() {
+    assert_eq!(
+        read_all_ok!("a\tb\0", |r| {
+            r.verify_no_bidi_controls()?;
+            Ok(r.take_remaining())
+        }),
+        "a\tb\0"
+    );
+    let error = read_all_err!("if x {} \u{202e} \u{2066}// y\u{2069}", |r| {
+        r.consume("if ")?;
+        r.verify_no_bidi_controls()
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), "\u{202e}".as_bytes());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_grapheme
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_read_grapheme() {
+    let (grapheme, rest) = read_partial_ok!("e\u{301}\u{1F1F3}\u{1F1FF}", |r| r.read_grapheme());
+    assert_eq!(grapheme, "e\u{301}");
+    assert_eq!(rest, "\u{1F1F3}\u{1F1FF}");
+
+    let flag = read_all_ok!("\u{1F1F3}\u{1F1FF}", |r| {
+        r.take_remaining()
+            .into_bound()
+            .read_all(|r| r.read_grapheme())
+    });
+    assert_eq!(flag, "\u{1F1F3}\u{1F1FF}");
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "retry"))]
+fn test_read_grapheme_unbound_end() {
+    // A grapheme reaching the end of unbound input may be extended.
+    assert_eq!(
+        read_all_err!("e", |r| r.read_grapheme()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+    assert_eq!(
+        read_all_err!("", |r| r.read_grapheme()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_graphemes
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_take_graphemes() {
+    let (graphemes, rest) = read_partial_ok!("a\r\ne\u{301}b", |r| r.take_graphemes(3));
+    assert_eq!(graphemes, "a\r\ne\u{301}");
+    assert_eq!(rest, "b");
+    assert_eq!(read_partial_ok!("ab", |r| r.take_graphemes(0)).0, "");
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "retry"))]
+fn test_take_graphemes_too_few() {
+    assert_eq!(
+        read_all_err!("ab", |r| r.take_graphemes(4)).to_retry_requirement(),
+        RetryRequirement::new(2)
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_while_xid_continue
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_take_while_xid_continue() {
+    let (ident, rest) = read_partial_ok!("1größe_2-x", |r| Ok(r.take_while_xid_continue()));
+    assert_eq!(ident, "1größe_2");
+    assert_eq!(rest, "-x");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_identifier
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_take_identifier() {
+    let (ident, rest) = read_partial_ok!("変数_1 = 1", |r| r.take_identifier());
+    assert_eq!(ident, "変数_1");
+    assert_eq!(rest, " = 1");
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn test_take_identifier_invalid_start() {
+    let error = read_all_err!("1abc", |r| r.take_identifier());
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"1"[..]);
+
+    let error = read_all_err!("ab\u{2603}", |r| {
+        assert_eq!(r.take_identifier()?, "ab");
+        r.take_identifier()
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), "\u{2603}".as_bytes());
+}
+
+#[test]
+#[cfg(all(feature = "unicode", feature = "full-context"))]
+fn test_take_identifier_invalid_start_display() {
+    let error = read_all_err!("-abc", |r| r.take_identifier());
+    assert_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            error attempting to take identifier: expected identifier start
+            > "-abc"
+               ^    
+            additional:
+              error line: 1, error offset: 0, input length: 4
+            backtrace:
+              1. `read all`
+              2. `take identifier` (expected identifier start)
+        "#}
+    );
+}
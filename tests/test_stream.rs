@@ -0,0 +1,353 @@
+#![allow(clippy::result_large_err)]
+#![cfg(all(feature = "std", feature = "retry"))]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::stream::{StreamDecoder, StreamError};
+use std::io;
+
+/// A reader that reads one byte at a time, interrupting every other read.
+struct Trickle<'a> {
+    src: &'a [u8],
+    interrupt: bool,
+}
+
+impl<'a> Trickle<'a> {
+    fn new(src: &'a [u8]) -> Self {
+        Self {
+            src,
+            interrupt: false,
+        }
+    }
+}
+
+impl<'a> io::Read for Trickle<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        match self.src.split_first() {
+            Some((byte, rest)) => {
+                buf[0] = *byte;
+                self.src = rest;
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+}
+
+fn decode_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<Vec<u8>, Expected<'i>> {
+    let len = r.read_u8()?;
+    Ok(r.take(len as usize)?.as_dangerous().to_vec())
+}
+
+#[test]
+fn test_stream_decoder_messages() {
+    let mut decoder = StreamDecoder::new(Trickle::new(b"\x02hi\x00\x03you"));
+    let messages: Vec<_> = decoder
+        .messages(decode_message)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(messages, [&b"hi"[..], b"", b"you"]);
+    assert!(decoder.buffered().is_empty());
+    assert!(decoder.decode(decode_message).unwrap().is_none());
+}
+
+#[test]
+fn test_stream_decoder_messages_consuming_nothing() {
+    let mut decoder = StreamDecoder::new(&b"hi"[..]);
+    let messages: Vec<_> = decoder
+        .messages(|r| r.peek_u8())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(messages, [b'h']);
+    assert_eq!(decoder.buffered(), b"hi");
+}
+
+#[test]
+fn test_stream_decoder_compacts() {
+    // Each message is 3 bytes, which only fit within the maximum buffer
+    // length if consumed bytes are compacted.
+    let stream = b"\x02ab".repeat(100);
+    let mut decoder = StreamDecoder::new(&stream[..]).with_max_buffer_len(4);
+
+    assert_eq!(decoder.max_buffer_len(), 4);
+    assert_eq!(decoder.messages(decode_message).count(), 100);
+}
+
+#[test]
+fn test_stream_decoder_message_too_large() {
+    let mut decoder = StreamDecoder::new(&b"\x08abc"[..]).with_max_buffer_len(4);
+    let error = decoder.decode(decode_message).unwrap_err();
+
+    assert!(matches!(
+        error,
+        StreamError::MessageTooLarge {
+            required: 9,
+            max_len: 4
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "message requires at least 9 bytes which exceeds the maximum buffer length of 4 bytes"
+    );
+}
+
+#[test]
+fn test_stream_decoder_unexpected_eof() {
+    let mut decoder = StreamDecoder::new(Trickle::new(b"\x02hi\x03yo"));
+
+    assert_eq!(decoder.decode(decode_message).unwrap().unwrap(), b"hi");
+    match decoder.decode(decode_message).unwrap_err() {
+        StreamError::Io(err) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+        err => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(decoder.buffered(), b"\x03yo");
+}
+
+fn decode_invalid() -> OwnedExpected {
+    let mut decoder = StreamDecoder::new(Trickle::new(b"v2\x00"));
+    let mut messages = decoder.messages(|r| {
+        r.context("message", |r| {
+            r.consume(b"v1")?;
+            decode_message(r)
+        })
+    });
+
+    let error = messages.next().unwrap().unwrap_err();
+    assert!(messages.next().is_none());
+    match error {
+        StreamError::Invalid(err) => err,
+        err => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_stream_decoder_invalid() {
+    let error = decode_invalid();
+    assert!(error.is_fatal());
+    assert_eq!((&error).span(), b"v2"[..]);
+    assert_eq!((&error).expected().unwrap().as_bytes(), b"v1");
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_stream_decoder_invalid_display() {
+    assert_eq!(
+        format!("{}\n", decode_invalid()),
+        indoc! {r#"
+            error attempting to consume: found a different value to the exact expected
+            expected:
+            > [76 31]
+            in:
+            > [76 32]
+               ^^ ^^ 
+            additional:
+              error offset: 0, input length: 2
+            backtrace:
+              1. `read partial`
+              2. `read` (expected message)
+              3. `consume` (expected exact value)
+        "#}
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// AsyncStreamDecoder
+
+#[cfg(feature = "futures-io")]
+mod async_decoder {
+    use super::*;
+
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use dangerous::stream::AsyncStreamDecoder;
+    use futures_executor::block_on;
+    use futures_io::AsyncRead;
+
+    /// An async reader that reads at most two bytes at a time, returning
+    /// pending every other poll.
+    struct Chunked<'a> {
+        src: &'a [u8],
+        pending: bool,
+    }
+
+    impl<'a> Chunked<'a> {
+        fn new(src: &'a [u8]) -> Self {
+            Self {
+                src,
+                pending: false,
+            }
+        }
+    }
+
+    impl<'a> AsyncRead for Chunked<'a> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            self.pending = !self.pending;
+            if self.pending {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let len = self.src.len().min(buf.len()).min(2);
+            buf[..len].copy_from_slice(&self.src[..len]);
+            self.src = &self.src[len..];
+            Poll::Ready(Ok(len))
+        }
+    }
+
+    #[test]
+    fn test_async_stream_decoder_messages() {
+        let mut reader = Chunked::new(b"\x02hi\x00\x03you");
+        let mut decoder = AsyncStreamDecoder::new();
+        let messages = block_on(async {
+            let mut messages = Vec::new();
+            while let Some(message) = decoder
+                .next_message(&mut reader, decode_message)
+                .await
+                .unwrap()
+            {
+                messages.push(message);
+            }
+            messages
+        });
+
+        assert_eq!(messages, [&b"hi"[..], b"", b"you"]);
+        assert!(decoder.buffered().is_empty());
+    }
+
+    #[test]
+    fn test_async_stream_decoder_compacts() {
+        let stream = b"\x02ab".repeat(100);
+        let mut reader = &stream[..];
+        let mut decoder = AsyncStreamDecoder::new().with_max_buffer_len(4);
+        let count = block_on(async {
+            let mut count = 0;
+            while decoder
+                .next_message(&mut reader, decode_message)
+                .await
+                .unwrap()
+                .is_some()
+            {
+                count += 1;
+            }
+            count
+        });
+
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn test_async_stream_decoder_errors() {
+        let mut decoder = AsyncStreamDecoder::new().with_max_buffer_len(4);
+        let error = block_on(decoder.next_message(&mut Chunked::new(b"\x08abc"), decode_message))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            StreamError::MessageTooLarge {
+                required: 9,
+                max_len: 4
+            }
+        ));
+
+        let mut decoder = AsyncStreamDecoder::new();
+        let error = block_on(decoder.next_message(&mut Chunked::new(b"\x03yo"), decode_message))
+            .unwrap_err();
+        assert!(
+            matches!(error, StreamError::Io(err) if err.kind() == io::ErrorKind::UnexpectedEof)
+        );
+
+        let mut decoder = AsyncStreamDecoder::new();
+        let error = block_on(decoder.next_message(&mut Chunked::new(b"v2"), |r| {
+            r.consume(b"v1")?;
+            decode_message(r)
+        }))
+        .unwrap_err();
+        assert!(matches!(error, StreamError::Invalid(err) if err.is_fatal()));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// StreamCodec
+
+#[cfg(feature = "tokio-codec")]
+mod codec {
+    use super::*;
+
+    use bytes::BytesMut;
+    use dangerous::stream::StreamCodec;
+    use tokio_util::codec::Decoder;
+
+    #[test]
+    fn test_stream_codec_retry() {
+        let mut codec = StreamCodec::new(decode_message);
+        let mut buf = BytesMut::new();
+        let mut messages = Vec::new();
+        for byte in b"\x02hi\x00\x03you" {
+            buf.extend_from_slice(&[*byte]);
+            while let Some(message) = codec.decode(&mut buf).unwrap() {
+                messages.push(message);
+            }
+        }
+
+        assert_eq!(messages, [&b"hi"[..], b"", b"you"]);
+        assert!(buf.is_empty());
+        assert_eq!(codec.decode_eof(&mut buf).unwrap(), None);
+    }
+
+    #[test]
+    fn test_stream_codec_reserves() {
+        let mut codec = StreamCodec::new(decode_message);
+        let mut buf = BytesMut::from(&b"\x10"[..]);
+
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        assert!(buf.capacity() >= 17);
+        assert!(matches!(
+            codec.decode_eof(&mut buf).unwrap_err(),
+            StreamError::Io(_)
+        ));
+    }
+
+    #[test]
+    fn test_stream_codec_message_too_large() {
+        let mut codec = StreamCodec::new(decode_message).with_max_buffer_len(4);
+        let mut buf = BytesMut::from(&b"\x08abc"[..]);
+
+        assert_eq!(codec.max_buffer_len(), 4);
+        assert!(matches!(
+            codec.decode(&mut buf).unwrap_err(),
+            StreamError::MessageTooLarge {
+                required: 9,
+                max_len: 4
+            }
+        ));
+    }
+
+    #[test]
+    fn test_stream_codec_invalid() {
+        let mut codec = StreamCodec::new(|r: &mut BytesReader<'_, Expected<'_>>| {
+            r.consume(b"v1")?;
+            decode_message(r)
+        });
+        let mut buf = BytesMut::from(&b"v"[..]);
+
+        assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        buf.extend_from_slice(b"2");
+        match codec.decode(&mut buf).unwrap_err() {
+            StreamError::Invalid(err) => {
+                assert!(err.to_string().starts_with("error attempting to consume"))
+            }
+            err => panic!("unexpected error: {:?}", err),
+        }
+        assert_eq!(&buf[..], b"v2");
+    }
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::result_large_err)]
+#![cfg(feature = "testing")]
+
+#[macro_use]
+mod common;
+
+use common::*;
+use dangerous::testing;
+
+fn read_message<'i>(r: &mut BytesReader<'i, Expected<'i>>) -> Result<(), Expected<'i>> {
+    let len = r.read_u8()?;
+    let _ = r.take(len as usize)?;
+    r.consume(b';')
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Invariants upheld
+
+#[test]
+fn test_check_ok() {
+    for input in &[
+        &b""[..],
+        b"\x00;",
+        b"\x02hi;",
+        b"\x02hi;rest",
+        b"\x02hi!",
+        b"\x05hi",
+    ] {
+        testing::check(input, read_message);
+        testing::check(input, |r| {
+            let len = r.take_while(|b| b != b';').len();
+            r.consume(b';')?;
+            Ok(len)
+        });
+        testing::check(input, |r| Ok((r.read_u16_le()?, r.read_u16_le()?)));
+    }
+}
+
+#[test]
+#[cfg(feature = "proptest")]
+fn test_check_with_proptest_ok() {
+    testing::check_with_proptest(read_message);
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn test_check_arbitrary_bytes() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let u = Unstructured::new(b"\x02hi;\x03");
+    let input = Bytes::arbitrary_take_rest(u).unwrap();
+    assert_eq!(input, b"\x02hi;\x03"[..]);
+    assert!(!input.is_bound());
+    testing::check(input.as_dangerous(), read_message);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Invariants violated
+
+#[test]
+#[should_panic(expected = "panicked")]
+fn test_check_panic() {
+    testing::check(b"\x00", |r| {
+        if r.read_u8()? == 0 {
+            panic!("zero");
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[should_panic(expected = "error with input not within it")]
+fn test_check_error_input_outside() {
+    testing::check(b"\x00", |r| {
+        r.read_u8()?;
+        dangerous::input(b"\xff").read_all(|r| r.read_u16_le())
+    });
+}
+
+#[test]
+#[should_panic(expected = "failed fatally but the full input was read")]
+fn test_check_fatal_prefix() {
+    testing::check(b"\x01\x02", |r| {
+        r.take_remaining()
+            .into_bound()
+            .read_all(|r| r.read_u16_le())
+    });
+}
+
+#[test]
+#[should_panic(expected = "required 2 bytes more but")]
+fn test_check_retry_overstated() {
+    testing::check(b"\x01", |r| {
+        r.try_expect_erased("byte", |r| {
+            r.read_u8().map_err(|_| RetryRequirement::new(2))
+        })
+    });
+}
+
+#[test]
+#[should_panic(expected = "failed the same again")]
+fn test_check_retry_not_honoured() {
+    testing::check(b"\x01\x02", |r| {
+        r.try_expect_erased("value", |_| Err::<(), _>(RetryRequirement::new(1)))
+    });
+}
@@ -0,0 +1,185 @@
+#![allow(clippy::result_large_err)]
+
+#[macro_use]
+mod common;
+
+use common::*;
+
+fn encode(s: &str, endian: Endian) -> Vec<u8> {
+    s.encode_utf16()
+        .flat_map(|unit| match endian {
+            Endian::Big => unit.to_be_bytes(),
+            Endian::Little => unit.to_le_bytes(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_read_char() {
+    for endian in [Endian::Little, Endian::Big] {
+        let bytes = encode("a♥𝄞", endian);
+        let chars = input!(bytes[..])
+            .into_utf16(endian)
+            .read_all::<_, _, Expected>(|r| Ok((r.read_char()?, r.read_char()?, r.read_char()?)))
+            .unwrap();
+        assert_eq!(chars, ('a', '♥', '𝄞'));
+    }
+}
+
+#[test]
+fn test_peek_char() {
+    let bytes = encode("𝄞", Endian::Big);
+    let input = input!(bytes[..]).into_utf16(Endian::Big);
+    input
+        .read_all::<_, _, Expected>(|r| {
+            assert_eq!(r.peek_char_opt(), Some('𝄞'));
+            assert_eq!(r.peek_char()?, '𝄞');
+            r.read_char()?;
+            assert_eq!(r.peek_char_opt(), None);
+            assert!(r.at_end());
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_take_while_and_consume() {
+    let bytes = encode("héllo, world", Endian::Little);
+    let (hello, world) = input!(bytes[..])
+        .into_utf16(Endian::Little)
+        .read_all::<_, _, Expected>(|r| {
+            let hello = r.take_while(char::is_alphabetic)?;
+            r.consume(",")?;
+            r.skip_while(char::is_whitespace)?;
+            Ok((hello, r.take_remaining()))
+        })
+        .unwrap();
+
+    assert_eq!(hello, *"héllo");
+    assert_eq!(world, *"world");
+    assert_eq!(hello.len(), 10);
+    assert_eq!(hello.endian(), Endian::Little);
+}
+
+#[test]
+fn test_eq_across_endian() {
+    let le = encode("𝄞!", Endian::Little);
+    let be = encode("𝄞!", Endian::Big);
+    assert_eq!(
+        input!(le[..]).into_utf16(Endian::Little),
+        input!(be[..]).into_utf16(Endian::Big)
+    );
+    assert_ne!(
+        input!(le[..]).into_utf16(Endian::Little),
+        input!(le[..]).into_utf16(Endian::Big)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_dangerous_string() {
+    let bytes = encode("a♥𝄞", Endian::Big);
+    let s = input!(bytes[..])
+        .into_utf16(Endian::Big)
+        .to_dangerous_string::<Expected>()
+        .unwrap();
+    assert_eq!(s, "a♥𝄞");
+
+    let error = input!(b"a\x00\x00\xdcb\x00")
+        .into_utf16(Endian::Little)
+        .to_dangerous_string::<Expected>()
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\x00\xdc"[..]);
+}
+
+#[test]
+fn test_unpaired_surrogate() {
+    // A high surrogate followed by a non-surrogate.
+    let error = input!(b"\xd8\x00\x00a")
+        .into_utf16(Endian::Big)
+        .read_all::<_, _, Expected>(|r| r.read_char())
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"\xd8\x00"[..]);
+}
+
+#[test]
+#[cfg(feature = "retry")]
+fn test_retry() {
+    // A high surrogate cut short of its low surrogate.
+    let error = input!(b"\x34\xd8")
+        .into_utf16(Endian::Little)
+        .read_all::<_, _, Expected>(|r| r.read_char())
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+
+    // An odd trailing byte.
+    let error = input!(b"a\x00b")
+        .into_utf16(Endian::Little)
+        .read_all::<_, _, Expected>(|r| r.take_while(|_| true))
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+
+    // A consumed value cut short.
+    let bytes = encode("hel", Endian::Little);
+    let error = input!(bytes[..])
+        .into_utf16(Endian::Little)
+        .read_all::<_, _, Expected>(|r| r.consume("hello"))
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(4));
+
+    // Bound input is fatal.
+    let error = input!(b"a\x00b")
+        .into_utf16(Endian::Little)
+        .into_bound()
+        .read_all::<_, _, Expected>(|r| r.take_while(|_| true))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_consume_mismatch() {
+    let bytes = encode("hi!", Endian::Little);
+    let error = input!(bytes[..])
+        .into_utf16(Endian::Little)
+        .read_all::<_, _, Expected>(|r| r.consume("hi?"))
+        .unwrap_err();
+    assert!(error.is_fatal());
+    assert_eq!(error.span(), b"!\x00"[..]);
+    assert_eq!(error.expected().unwrap().as_bytes(), b"?\x00");
+}
+
+#[test]
+#[cfg(feature = "full-context")]
+fn test_consume_mismatch_display() {
+    let bytes = encode("hi!", Endian::Big);
+    let error = input!(bytes[..])
+        .into_utf16(Endian::Big)
+        .read_all::<_, _, Expected>(|r| r.consume("hi?"))
+        .unwrap_err();
+    let expected = indoc! {r#"
+        error attempting to consume: found a different value to the exact expected
+        expected:
+        > "?"
+        in:
+        > "hi!"
+             ^ 
+        additional:
+          error offset: 4, input length: 6
+        backtrace:
+          1. `read all`
+          2. `consume` (expected exact value)
+    "#};
+    assert_eq!(format!("{}\n", error), expected);
+    assert_eq!(format!("{}\n", error.into_owned()), expected);
+}
+
+#[test]
+fn test_debug() {
+    let input = input!(b"h\x00i\x00").into_utf16(Endian::Little);
+    assert_eq!(
+        format!("{:?}", input),
+        r#"Utf16 { bound: Start, endian: Little, value: "hi" }"#
+    );
+}